@@ -91,13 +91,29 @@ fn testnet_genesis(
         grandpa: Default::default(),
         sudo: SudoConfig {
             // Assign network admin rights to root key.
-            key: Some(root_key),
+            key: Some(root_key.clone()),
         },
         transaction_payment: Default::default(),
         aura_identity: AuraIdentityConfig {
-            // Initialize identity pallet
-            identities: Default::default(),
-            next_identity_id: Default::default(),
+            // sr25519 accounts are their own public key, so every endowed
+            // account starts with an Aura ID bound to its account key.
+            identities: endowed_accounts
+                .iter()
+                .cloned()
+                .map(|k| (k.clone(), k.into()))
+                .collect(),
+            // The root key can be recovered by two of the next two endowed accounts.
+            recovery_setups: vec![(
+                root_key.clone(),
+                2,
+                endowed_accounts
+                    .iter()
+                    .filter(|k| **k != root_key)
+                    .take(2)
+                    .cloned()
+                    .collect(),
+                None,
+            )],
         },
     }
 }
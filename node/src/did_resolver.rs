@@ -0,0 +1,471 @@
+//! `did:aura` DID resolver.
+//!
+//! Queries the `AuraIdentity` runtime API at a given block and renders the DID
+//! document as a W3C DID Resolution result: a JSON-LD DID document together
+//! with resolution and document metadata. Valid claims about the DID from the
+//! `AuraCredentials` runtime API that the subject has not hidden are listed
+//! under `claims`.
+
+use aura_chain_runtime::{opaque::Block, AccountId, Balance, BlockNumber};
+use pallet_aura_credentials_runtime_api::{AuraCredentialsRuntimeApi, Claim};
+use pallet_aura_identity::{VerificationKeyType, VerificationPurpose};
+use pallet_aura_identity_runtime_api::{AuraIdentityRuntimeApi, DidDocument, Tombstone};
+use serde_json::{json, Map, Value};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::hexdisplay::HexDisplay;
+use sp_runtime::traits::Block as BlockT;
+
+/// Method prefix of every Aura DID.
+pub const DID_PREFIX: &str = "did:aura:";
+
+/// Fragment of the verification method built from `AuraIdRecord.public_key`.
+pub const PRIMARY_KEY_FRAGMENT: &str = "primary";
+
+/// Everything that makes up a DID document, queried at a single block.
+pub struct DidDocumentState {
+    pub did: [u8; 32],
+    pub document: DidDocument<Balance, BlockNumber>,
+    /// Valid claims about the DID that are not hidden by the subject.
+    pub claims: Vec<([u8; 32], Claim<AccountId, Balance, BlockNumber>)>,
+}
+
+/// Parses `did:aura:<64 hex chars>` into the raw DID.
+pub fn parse_did(did: &str) -> Option<[u8; 32]> {
+    let id = did.strip_prefix(DID_PREFIX)?;
+    if id.len() != 64 || id.starts_with("0x") {
+        return None;
+    }
+    sp_core::bytes::from_hex(id).ok()?.try_into().ok()
+}
+
+/// Renders a raw DID as `did:aura:<hex>`.
+pub fn format_did(did: &[u8; 32]) -> String {
+    format!("{}{}", DID_PREFIX, HexDisplay::from(did))
+}
+
+/// Loads the DID document state for `did` at block `at`, or `None` if the DID
+/// is unknown. Runtime API failures, including undecodable state, are errors.
+pub fn load_document<C>(
+    client: &C,
+    at: <Block as BlockT>::Hash,
+    did: [u8; 32],
+) -> sp_blockchain::Result<Option<DidDocumentState>>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: AuraIdentityRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + AuraCredentialsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+    let Some(document) = client.runtime_api().did_document(at, did)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(DidDocumentState {
+        did,
+        document,
+        claims: load_visible_claims(client, at, did)?,
+    }))
+}
+
+/// Claims about `did` that are neither hidden, expired nor issued by a deactivated DID.
+/// The runtime caps claims per subject at `MaxClaimsPerSubject`, which bounds this list.
+fn load_visible_claims<C>(
+    client: &C,
+    at: <Block as BlockT>::Hash,
+    did: [u8; 32],
+) -> sp_blockchain::Result<Vec<([u8; 32], Claim<AccountId, Balance, BlockNumber>)>>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: AuraIdentityRuntimeApi<Block, AccountId, Balance, BlockNumber>
+        + AuraCredentialsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+    let now = client
+        .number(at)?
+        .ok_or_else(|| sp_blockchain::Error::UnknownBlock(format!("{:?}", at)))?;
+    let api = client.runtime_api();
+    let mut claims = Vec::new();
+    for (claim_id, claim) in api.claims_of(at, did, false)? {
+        if claim.expiry.map_or(false, |expiry| now > expiry) {
+            continue;
+        }
+        if api.tombstone(at, claim.issuer)?.is_some() {
+            continue;
+        }
+        claims.push((claim_id, claim));
+    }
+    Ok(claims)
+}
+
+/// Loads the tombstone of `did` at block `at`, if the DID was deactivated.
+pub fn load_tombstone<C>(
+    client: &C,
+    at: <Block as BlockT>::Hash,
+    did: [u8; 32],
+) -> sp_blockchain::Result<Option<Tombstone<AccountId, BlockNumber>>>
+where
+    C: ProvideRuntimeApi<Block>,
+    C::Api: AuraIdentityRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+    Ok(client.runtime_api().tombstone(at, did)?)
+}
+
+/// W3C DID Resolution result for a failed resolution.
+pub fn resolution_error(error: &str) -> Value {
+    json!({
+        "@context": "https://w3id.org/did-resolution/v1",
+        "didDocument": Value::Null,
+        "didResolutionMetadata": { "error": error },
+        "didDocumentMetadata": {},
+    })
+}
+
+/// W3C DID Resolution result for a resolved document.
+pub fn resolution_result(state: &DidDocumentState) -> Value {
+    // The chain has no wall clock, so times are expressed as block numbers.
+    let mut metadata = json!({
+        "updated": state.document.version.updated,
+        "deactivated": false,
+        "versionId": state.document.version.version_id.to_string(),
+    });
+    // Aura IDs registered before creation blocks were recorded have no `created`.
+    if let Some(created) = state.document.record.created {
+        metadata["created"] = json!(created);
+    }
+
+    json!({
+        "@context": "https://w3id.org/did-resolution/v1",
+        "didDocument": did_document(state),
+        "didResolutionMetadata": { "contentType": "application/did+ld+json" },
+        "didDocumentMetadata": metadata,
+    })
+}
+
+/// W3C DID Resolution result for a deactivated DID: an empty document with
+/// `deactivated: true` in the document metadata.
+pub fn deactivated_result(did: &[u8; 32], tombstone: &Tombstone<AccountId, BlockNumber>) -> Value {
+    json!({
+        "@context": "https://w3id.org/did-resolution/v1",
+        "didDocument": {
+            "@context": ["https://www.w3.org/ns/did/v1"],
+            "id": format_did(did),
+        },
+        "didResolutionMetadata": { "contentType": "application/did+ld+json" },
+        "didDocumentMetadata": {
+            "updated": tombstone.deactivated_at,
+            "deactivated": true,
+            "versionId": tombstone.version.version_id.to_string(),
+        },
+    })
+}
+
+fn did_document(state: &DidDocumentState) -> Value {
+    let id = format_did(&state.did);
+    let mut methods = Vec::new();
+    let mut relationships: Map<String, Value> = Map::new();
+
+    // The primary Aura ID key can authenticate and act on behalf of the DID.
+    // A key migrated from storage version 0 has no known algorithm and is
+    // omitted until the owner rotates it.
+    let public_key = &state.document.record.public_key;
+    if let Some(key_type) = public_key.key_type() {
+        let primary = format!("{}#{}", id, PRIMARY_KEY_FRAGMENT);
+        methods.push(verification_method(&primary, &id, key_type, public_key.as_bytes()));
+        for purpose in [
+            VerificationPurpose::Authentication,
+            VerificationPurpose::AssertionMethod,
+            VerificationPurpose::CapabilityInvocation,
+            VerificationPurpose::CapabilityDelegation,
+        ] {
+            push_relationship(&mut relationships, purpose, &primary);
+        }
+    }
+
+    for (fragment, method) in &state.document.methods {
+        let method_id = format!("{}#{}", id, String::from_utf8_lossy(fragment));
+        let controller = method.controller.as_ref().map_or_else(|| id.clone(), format_did);
+        methods.push(verification_method(&method_id, &controller, method.key_type, &method.public_key));
+        for purpose in method.purposes.iter() {
+            push_relationship(&mut relationships, *purpose, &method_id);
+        }
+    }
+
+    let services: Vec<Value> = state
+        .document
+        .services
+        .iter()
+        .map(|(fragment, service)| {
+            json!({
+                "id": format!("{}#{}", id, String::from_utf8_lossy(fragment)),
+                "type": String::from_utf8_lossy(&service.service_type),
+                "serviceEndpoint": String::from_utf8_lossy(&service.endpoint),
+            })
+        })
+        .collect();
+
+    let mut document = Map::new();
+    document.insert("@context".into(), json!(["https://www.w3.org/ns/did/v1"]));
+    document.insert("id".into(), json!(id));
+    if !state.document.controllers.is_empty() {
+        let controllers: Vec<String> = state.document.controllers.iter().map(format_did).collect();
+        document.insert("controller".into(), json!(controllers));
+    }
+    document.insert("verificationMethod".into(), Value::Array(methods));
+    document.extend(relationships);
+    if !services.is_empty() {
+        document.insert("service".into(), Value::Array(services));
+    }
+    if !state.claims.is_empty() {
+        let claims: Vec<Value> = state
+            .claims
+            .iter()
+            .map(|(claim_id, claim)| claim_entry(&id, claim_id, claim))
+            .collect();
+        document.insert("claims".into(), Value::Array(claims));
+    }
+    Value::Object(document)
+}
+
+fn claim_entry(id: &str, claim_id: &[u8; 32], claim: &Claim<AccountId, Balance, BlockNumber>) -> Value {
+    let mut entry = Map::new();
+    entry.insert("id".into(), json!(format!("{}#claim-{}", id, HexDisplay::from(claim_id))));
+    entry.insert("issuer".into(), json!(format_did(&claim.issuer)));
+    entry.insert("claimType".into(), json!(format!("0x{}", HexDisplay::from(&claim.claim_type))));
+    if let Some(payload_hash) = &claim.payload_hash {
+        entry.insert("payloadHash".into(), json!(format!("0x{}", HexDisplay::from(payload_hash))));
+    }
+    // Block numbers, like the document metadata times.
+    entry.insert("issued".into(), json!(claim.issued));
+    if let Some(expiry) = claim.expiry {
+        entry.insert("expires".into(), json!(expiry));
+    }
+    Value::Object(entry)
+}
+
+fn push_relationship(relationships: &mut Map<String, Value>, purpose: VerificationPurpose, method_id: &str) {
+    let name = match purpose {
+        VerificationPurpose::Authentication => "authentication",
+        VerificationPurpose::AssertionMethod => "assertionMethod",
+        VerificationPurpose::KeyAgreement => "keyAgreement",
+        VerificationPurpose::CapabilityInvocation => "capabilityInvocation",
+        VerificationPurpose::CapabilityDelegation => "capabilityDelegation",
+    };
+    if let Value::Array(ids) = relationships.entry(name).or_insert_with(|| json!([])) {
+        ids.push(json!(method_id));
+    }
+}
+
+fn verification_method(id: &str, controller: &str, key_type: VerificationKeyType, public_key: &[u8]) -> Value {
+    // Verification method type and multicodec prefix of the key.
+    let (method_type, codec): (&str, &[u8]) = match key_type {
+        VerificationKeyType::Sr25519 => ("Sr25519VerificationKey2020", &[0xef, 0x01]),
+        VerificationKeyType::Ed25519 => ("Ed25519VerificationKey2020", &[0xed, 0x01]),
+        VerificationKeyType::EcdsaSecp256k1 => ("EcdsaSecp256k1VerificationKey2019", &[0xe7, 0x01]),
+        VerificationKeyType::X25519 => ("X25519KeyAgreementKey2020", &[0xec, 0x01]),
+        VerificationKeyType::P256 => ("EcdsaSecp256r1VerificationKey2019", &[0x80, 0x24]),
+    };
+    let mut multicodec = codec.to_vec();
+    multicodec.extend_from_slice(public_key);
+
+    json!({
+        "id": id,
+        "type": method_type,
+        "controller": controller,
+        "publicKeyMultibase": format!("z{}", bs58::encode(multicodec).into_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pallet_aura_identity::{DocumentVersion, ServiceEndpoint, VerificationMethod};
+    use pallet_aura_identity_runtime_api::{AuraIdRecord, AuraPublicKey};
+
+    const DID: [u8; 32] = [0xab; 32];
+
+    fn state() -> DidDocumentState {
+        DidDocumentState {
+            did: DID,
+            document: DidDocument {
+                record: AuraIdRecord {
+                    did: DID,
+                    public_key: AuraPublicKey::Sr25519([1; 32]),
+                    recovery_config: Default::default(),
+                    created: Some(5),
+                },
+                methods: vec![(
+                    b"keys-1".to_vec().try_into().unwrap(),
+                    VerificationMethod {
+                        key_type: VerificationKeyType::X25519,
+                        public_key: vec![2; 32].try_into().unwrap(),
+                        controller: Some([0xcd; 32]),
+                        purposes: vec![VerificationPurpose::KeyAgreement].try_into().unwrap(),
+                        deposit: 0,
+                    },
+                )],
+                services: vec![(
+                    b"site".to_vec().try_into().unwrap(),
+                    ServiceEndpoint {
+                        service_type: b"LinkedDomains".to_vec().try_into().unwrap(),
+                        endpoint: b"https://aura.example".to_vec().try_into().unwrap(),
+                        deposit: 0,
+                    },
+                )],
+                controllers: vec![[0xcd; 32]],
+                version: DocumentVersion { version_id: 3, updated: 7 },
+            },
+            claims: vec![(
+                [0x11; 32],
+                Claim {
+                    issuer: [0xcd; 32],
+                    claim_type: [0x22; 32],
+                    payload_hash: None,
+                    expiry: Some(100),
+                    issued: 6,
+                    hidden: false,
+                    depositor: AccountId::from([0; 32]),
+                    deposit: 0,
+                },
+            )],
+        }
+    }
+
+    #[test]
+    fn parse_and_format_did() {
+        let did = format_did(&DID);
+        assert_eq!(did, format!("did:aura:{}", "ab".repeat(32)));
+        assert_eq!(parse_did(&did), Some(DID));
+
+        assert_eq!(parse_did(&format!("did:aura:0x{}", "ab".repeat(31))), None);
+        assert_eq!(parse_did(&format!("did:web:{}", "ab".repeat(32))), None);
+        assert_eq!(parse_did(&format!("did:aura:{}", "ab".repeat(31))), None);
+        assert_eq!(parse_did(&format!("did:aura:{}", "zz".repeat(32))), None);
+    }
+
+    #[test]
+    fn resolved_document_shape() {
+        let id = format_did(&DID);
+        let controller = format_did(&[0xcd; 32]);
+        let primary = format!("{}#primary", id);
+        let result = resolution_result(&state());
+
+        assert_eq!(result["didResolutionMetadata"], json!({ "contentType": "application/did+ld+json" }));
+        assert_eq!(
+            result["didDocumentMetadata"],
+            json!({ "created": 5, "updated": 7, "deactivated": false, "versionId": "3" })
+        );
+
+        let document = &result["didDocument"];
+        assert_eq!(document["id"], json!(id));
+        assert_eq!(document["controller"], json!([controller]));
+
+        let methods = document["verificationMethod"].as_array().unwrap();
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0]["id"], json!(primary));
+        assert_eq!(methods[0]["type"], json!("Sr25519VerificationKey2020"));
+        assert_eq!(methods[0]["controller"], json!(id));
+        // Multibase base58btc of the multicodec prefix followed by the key.
+        let multibase = methods[0]["publicKeyMultibase"].as_str().unwrap();
+        let decoded = bs58::decode(multibase.strip_prefix('z').unwrap()).into_vec().unwrap();
+        assert_eq!(decoded, [&[0xef, 0x01][..], &[1; 32]].concat());
+        assert_eq!(methods[1]["id"], json!(format!("{}#keys-1", id)));
+        assert_eq!(methods[1]["type"], json!("X25519KeyAgreementKey2020"));
+        assert_eq!(methods[1]["controller"], json!(controller));
+
+        for relationship in ["authentication", "assertionMethod", "capabilityInvocation", "capabilityDelegation"] {
+            assert_eq!(document[relationship], json!([primary]));
+        }
+        assert_eq!(document["keyAgreement"], json!([format!("{}#keys-1", id)]));
+
+        assert_eq!(
+            document["service"],
+            json!([{
+                "id": format!("{}#site", id),
+                "type": "LinkedDomains",
+                "serviceEndpoint": "https://aura.example",
+            }])
+        );
+        assert_eq!(
+            document["claims"],
+            json!([{
+                "id": format!("{}#claim-{}", id, "11".repeat(32)),
+                "issuer": controller,
+                "claimType": format!("0x{}", "22".repeat(32)),
+                "issued": 6,
+                "expires": 100,
+            }])
+        );
+    }
+
+    #[test]
+    fn unknown_creation_block_is_omitted() {
+        let mut state = state();
+        state.document.record.created = None;
+
+        assert_eq!(
+            resolution_result(&state)["didDocumentMetadata"],
+            json!({ "updated": 7, "deactivated": false, "versionId": "3" })
+        );
+    }
+
+    #[test]
+    fn legacy_primary_key_is_omitted() {
+        let mut state = state();
+        state.document.record.public_key = AuraPublicKey::Legacy([1; 32]);
+
+        let document = did_document(&state);
+        let methods = document["verificationMethod"].as_array().unwrap();
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0]["id"], json!(format!("{}#keys-1", format_did(&DID))));
+        assert!(document.get("authentication").is_none());
+        assert_eq!(document["keyAgreement"], json!([format!("{}#keys-1", format_did(&DID))]));
+    }
+
+    #[test]
+    fn minimal_document_omits_empty_sections() {
+        let mut state = state();
+        state.document.methods.clear();
+        state.document.services.clear();
+        state.document.controllers.clear();
+        state.claims.clear();
+
+        let document = did_document(&state);
+        let keys: Vec<&str> = document.as_object().unwrap().keys().map(String::as_str).collect();
+        assert!(!keys.contains(&"controller"));
+        assert!(!keys.contains(&"service"));
+        assert!(!keys.contains(&"claims"));
+        assert!(!keys.contains(&"keyAgreement"));
+        assert_eq!(document["verificationMethod"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn deactivated_and_not_found_results() {
+        let tombstone = Tombstone {
+            account: AccountId::from([0; 32]),
+            deactivated_at: 42,
+            forced: false,
+            version: DocumentVersion { version_id: 4, updated: 42 },
+        };
+        assert_eq!(
+            deactivated_result(&DID, &tombstone),
+            json!({
+                "@context": "https://w3id.org/did-resolution/v1",
+                "didDocument": {
+                    "@context": ["https://www.w3.org/ns/did/v1"],
+                    "id": format_did(&DID),
+                },
+                "didResolutionMetadata": { "contentType": "application/did+ld+json" },
+                "didDocumentMetadata": { "updated": 42, "deactivated": true, "versionId": "4" },
+            })
+        );
+
+        assert_eq!(
+            resolution_error("notFound"),
+            json!({
+                "@context": "https://w3id.org/did-resolution/v1",
+                "didDocument": null,
+                "didResolutionMetadata": { "error": "notFound" },
+                "didDocumentMetadata": {},
+            })
+        );
+    }
+}
@@ -6,6 +6,7 @@ use log::info;
 
 mod chain_spec;
 mod cli;
+mod did_resolver;
 mod service;
 mod rpc;
 
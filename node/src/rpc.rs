@@ -1,15 +1,50 @@
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use aura_chain_runtime::{opaque::Block, AccountId, Balance, Index};
+use aura_chain_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Index};
+use pallet_aura_credentials_runtime_api::{AuraCredentialsRuntimeApi, StatusPurpose};
+use pallet_aura_identity_runtime_api::{AuraIdentityRuntimeApi, AuraPublicKey};
+use serde::Serialize;
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_core::hexdisplay::HexDisplay;
+use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
+use crate::did_resolver;
+
+/// Aura ID of an account as returned by `auraidentity_getIdentity`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityInfo {
+    pub did: String,
+    /// Key algorithm: `sr25519`, `ed25519`, `ecdsa-secp256k1`, `p256`, or
+    /// `legacy` for a key migrated without its algorithm
+    pub key_type: String,
+    /// Hex-encoded key; compressed SEC1 point for ECDSA keys
+    pub public_key: String,
+    /// Registration block; omitted if it is unknown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<u32>,
+}
+
+fn key_type_name(key: &AuraPublicKey) -> &'static str {
+    match key {
+        AuraPublicKey::Sr25519(_) => "sr25519",
+        AuraPublicKey::Ed25519(_) => "ed25519",
+        AuraPublicKey::EcdsaSecp256k1(_) => "ecdsa-secp256k1",
+        AuraPublicKey::P256(_) => "p256",
+        AuraPublicKey::Legacy(_) => "legacy",
+    }
+}
+
 #[rpc]
 pub trait AuraIdentityRpc<BlockHash> {
     #[rpc(name = "auraidentity_getIdentity")]
-    fn get_identity(&self, account: AccountId, at: Option<BlockHash>) -> Result<Option<String>>;
+    fn get_identity(&self, account: AccountId, at: Option<BlockHash>) -> Result<Option<IdentityInfo>>;
+
+    /// Resolves a `did:aura:<id>` DID following the W3C DID Resolution contract.
+    #[rpc(name = "auraidentity_resolveDid")]
+    fn resolve_did(&self, did: String, at: Option<BlockHash>) -> Result<serde_json::Value>;
 }
 
 pub struct AuraIdentityRpcImpl<C> {
@@ -25,21 +60,131 @@ impl<C> AuraIdentityRpcImpl<C> {
 impl<C> AuraIdentityRpc<<Block as BlockT>::Hash> for AuraIdentityRpcImpl<C>
 where
     C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
-    C::Api: pallet_aura_identity_runtime_api::AuraIdentityRuntimeApi<Block, AccountId>,
+    C::Api: AuraIdentityRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: AuraCredentialsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
 {
-    fn get_identity(&self, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<String>> {
+    fn get_identity(&self, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<IdentityInfo>> {
         let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(||
+        let at = at.unwrap_or_else(||
             // If the block hash is not supplied assume the best block.
             self.client.info().best_hash
-        ));
+        );
 
-        api.get_identity(&at, account)
+        let record = api.identity(at, account)
             .map_err(|e| RpcError {
                 code: ErrorCode::ServerError(9876),
                 message: "Unable to query identity".into(),
                 data: Some(format!("{:?}", e).into()),
-            })
+            })?;
+
+        Ok(record.map(|record| IdentityInfo {
+            did: did_resolver::format_did(&record.did),
+            key_type: key_type_name(&record.public_key).into(),
+            public_key: format!("0x{}", HexDisplay::from(&record.public_key.as_bytes())),
+            created: record.created,
+        }))
+    }
+
+    fn resolve_did(&self, did: String, at: Option<<Block as BlockT>::Hash>) -> Result<serde_json::Value> {
+        let Some(raw_did) = did_resolver::parse_did(&did) else {
+            return Ok(did_resolver::resolution_error("invalidDid"));
+        };
+        // If the block hash is not supplied assume the best block.
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let resolve_error = |e: sp_blockchain::Error| RpcError {
+            code: ErrorCode::ServerError(9877),
+            message: "Unable to resolve DID".into(),
+            data: Some(format!("{:?}", e).into()),
+        };
+
+        if let Some(tombstone) = did_resolver::load_tombstone(&*self.client, at, raw_did).map_err(resolve_error)? {
+            return Ok(did_resolver::deactivated_result(&raw_did, &tombstone));
+        }
+
+        let state = did_resolver::load_document(&*self.client, at, raw_did).map_err(resolve_error)?;
+
+        Ok(match state {
+            Some(state) => did_resolver::resolution_result(&state),
+            None => did_resolver::resolution_error("notFound"),
+        })
+    }
+}
+
+/// Status of a credential as returned by `auracredentials_checkStatus`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialStatusInfo {
+    /// `statusPurpose` of the status list: `revocation` or `suspension`
+    pub status_purpose: String,
+    /// The status bit is set: the credential is revoked or suspended
+    pub status: bool,
+    /// Block of the last change to the status list
+    pub updated: u32,
+    /// The issuer is registered and its DID is not deactivated
+    pub issuer_active: bool,
+}
+
+#[rpc]
+pub trait AuraCredentialsRpc<BlockHash> {
+    /// Status of the credential at `index` in the issuer's status list `list_id`.
+    /// `None` if the list does not exist or the index is out of its bounds.
+    #[rpc(name = "auracredentials_checkStatus")]
+    fn check_status(
+        &self,
+        issuer_did: String,
+        list_id: u32,
+        index: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Option<CredentialStatusInfo>>;
+}
+
+pub struct AuraCredentialsRpcImpl<C> {
+    client: Arc<C>,
+}
+
+impl<C> AuraCredentialsRpcImpl<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> AuraCredentialsRpc<<Block as BlockT>::Hash> for AuraCredentialsRpcImpl<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AuraCredentialsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+    fn check_status(
+        &self,
+        issuer_did: String,
+        list_id: u32,
+        index: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<CredentialStatusInfo>> {
+        let issuer = did_resolver::parse_did(&issuer_did).ok_or_else(|| RpcError {
+            code: ErrorCode::InvalidParams,
+            message: "Invalid issuer DID".into(),
+            data: Some(issuer_did.clone().into()),
+        })?;
+        // If the block hash is not supplied assume the best block.
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let status = self.client.runtime_api().credential_status(at, issuer, list_id, index)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(9878),
+                message: "Unable to query credential status".into(),
+                data: Some(format!("{:?}", e).into()),
+            })?;
+
+        Ok(status.map(|status| CredentialStatusInfo {
+            status_purpose: match status.purpose {
+                StatusPurpose::Revocation => "revocation",
+                StatusPurpose::Suspension => "suspension",
+            }.into(),
+            status: status.status,
+            updated: status.updated,
+            issuer_active: status.issuer_active,
+        }))
     }
 }
 
@@ -60,7 +205,8 @@ where
         + Sync
         + 'static
         + sc_client_api::BlockBackend<Block>,
-    C::Api: pallet_aura_identity_runtime_api::AuraIdentityRuntimeApi<Block, AccountId>,
+    C::Api: AuraIdentityRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: AuraCredentialsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
 {
     let mut io = jsonrpc_core::IoHandler::default();
     let FullDeps {
@@ -72,6 +218,9 @@ where
     io.extend_with(
         crate::rpc::AuraIdentityRpc::to_delegate(AuraIdentityRpcImpl::new(client.clone()))
     );
+    io.extend_with(
+        crate::rpc::AuraCredentialsRpc::to_delegate(AuraCredentialsRpcImpl::new(client.clone()))
+    );
 
     io
 }
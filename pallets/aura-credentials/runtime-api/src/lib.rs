@@ -0,0 +1,39 @@
+//! Runtime API для Aura Credentials
+//!
+//! Запросы верификаторов: издатель по DID, схема по хешу, статус учетных
+//! данных в списке статусов издателя и утверждения о DID.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_aura_credentials::{Claim, CredentialSchema, CredentialStatus, Issuer, StatusPurpose};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(3)]
+	pub trait AuraCredentialsRuntimeApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Издатель, зарегистрированный по DID
+		fn issuer(did: [u8; 32]) -> Option<Issuer<AccountId, Balance, BlockNumber>>;
+
+		/// Схема учетных данных по хешу документа схемы
+		fn schema(schema_hash: [u8; 32]) -> Option<CredentialSchema<Balance, BlockNumber>>;
+
+		/// Статус учетных данных с индексом `index` в списке `list_id` издателя
+		fn credential_status(issuer: [u8; 32], list_id: u32, index: u32) -> Option<CredentialStatus<BlockNumber>>;
+
+		/// Утверждения о субъекте; скрытые субъектом - только при `include_hidden`.
+		/// Их не больше `MaxClaimsPerSubject`.
+		#[api_version(2)]
+		fn claims_of(subject: [u8; 32], include_hidden: bool) -> Vec<([u8; 32], Claim<AccountId, Balance, BlockNumber>)>;
+
+		/// Страница утверждений издателя: идентификатор утверждения и DID
+		/// субъекта, начиная после `start_after`, не больше `limit` записей
+		#[api_version(3)]
+		fn claims_issued_by(issuer: [u8; 32], start_after: Option<[u8; 32]>, limit: u32) -> Vec<([u8; 32], [u8; 32])>;
+	}
+}
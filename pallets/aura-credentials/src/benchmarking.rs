@@ -0,0 +1,226 @@
+//! Бенчмарки паллета aura-credentials
+
+use super::*;
+use crate::Pallet as AuraCredentials;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	pallet_prelude::Encode,
+	traits::{Currency, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_aura_identity::{AuraIdRecord, AuraIdentities, AuraPublicKey, DidIndex};
+use sp_runtime::traits::{Bounded, StaticLookup};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(event.into());
+}
+
+/// Аккаунт с балансом и Aura ID. Запись вставляется напрямую: доказательство
+/// владения ключом измеряется в бенчмарках aura-identity.
+fn identity<T: Config>(index: u32) -> (T::AccountId, [u8; 32]) {
+	let who: T::AccountId = account("aura_id", index, SEED);
+	<T as pallet_aura_identity::Config>::Currency::make_free_balance_be(
+		&who,
+		BalanceOf::<T>::max_value() / 4u32.into(),
+	);
+
+	let did = sp_io::hashing::blake2_256(&who.encode());
+	AuraIdentities::<T>::insert(&who, AuraIdRecord {
+		did,
+		public_key: AuraPublicKey::Sr25519(did),
+		recovery_config: BoundedVec::default(),
+		created: Some(frame_system::Pallet::<T>::block_number()),
+	});
+	DidIndex::<T>::insert(did, who.clone());
+	(who, did)
+}
+
+fn issuer<T: Config>() -> (T::AccountId, [u8; 32]) {
+	let (who, did) = identity::<T>(0);
+	AuraCredentials::<T>::register_issuer(RawOrigin::Signed(who.clone()).into())
+		.expect("account with an Aura ID can register as issuer");
+	(who, did)
+}
+
+/// Список статусов максимального размера
+fn status_list<T: Config>(who: &T::AccountId, purpose: StatusPurpose) {
+	AuraCredentials::<T>::create_status_list(
+		RawOrigin::Signed(who.clone()).into(),
+		purpose,
+		T::MaxStatusListSize::get(),
+	)
+	.expect("issuer is below the status list limit");
+}
+
+/// Утверждение `who` о `subject` с идентификатором
+fn attested_claim<T: Config>(who: &T::AccountId, issuer: &[u8; 32], subject: [u8; 32]) -> [u8; 32] {
+	let claim_type = [1u8; 32];
+	AuraCredentials::<T>::attest_claim(
+		RawOrigin::Signed(who.clone()).into(),
+		T::DidLookup::unlookup(subject),
+		claim_type,
+		None,
+		Some([2u8; 32]),
+	)
+	.expect("issuer can attest a new claim about another Aura ID");
+	AuraCredentials::<T>::claim_id(issuer, &subject, &claim_type)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_issuer() {
+		let (who, did) = identity::<T>(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()));
+
+		assert!(Issuers::<T>::contains_key(did));
+		assert_last_event::<T>(Event::IssuerRegistered { did, account: who }.into());
+	}
+
+	#[benchmark]
+	fn unregister_issuer() {
+		let (who, did) = issuer::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), T::DidLookup::unlookup(did));
+
+		assert!(!Issuers::<T>::contains_key(did));
+	}
+
+	#[benchmark]
+	fn publish_schema() {
+		let (who, did) = issuer::<T>();
+		let schema_hash = [1u8; 32];
+		let uri = vec![b'a'; MAX_SCHEMA_URI_LENGTH as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), schema_hash, uri);
+
+		assert_last_event::<T>(Event::SchemaPublished { schema_hash, issuer: did }.into());
+	}
+
+	#[benchmark]
+	fn remove_schema() {
+		let (who, _) = issuer::<T>();
+		let schema_hash = [1u8; 32];
+		AuraCredentials::<T>::publish_schema(
+			RawOrigin::Signed(who.clone()).into(),
+			schema_hash,
+			vec![b'a'; MAX_SCHEMA_URI_LENGTH as usize],
+		)
+		.expect("issuer can publish a new schema");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), schema_hash);
+
+		assert!(!Schemas::<T>::contains_key(schema_hash));
+	}
+
+	// Худший случай: у издателя уже `MaxStatusLists - 1` списков
+	#[benchmark]
+	fn create_status_list(s: Linear<1, 16_384>) {
+		let (who, did) = issuer::<T>();
+		for _ in 1..T::MaxStatusLists::get() {
+			status_list::<T>(&who, StatusPurpose::Revocation);
+		}
+		let size = s.min(T::MaxStatusListSize::get());
+		let list_id = NextStatusListId::<T>::get(did);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), StatusPurpose::Revocation, size);
+
+		assert_eq!(StatusLists::<T>::get(did, list_id).map(|list| list.bits.len() as u32), Some(size));
+	}
+
+	#[benchmark]
+	fn update_status(u: Linear<0, 256>) {
+		let (who, did) = issuer::<T>();
+		status_list::<T>(&who, StatusPurpose::Suspension);
+		let capacity = T::MaxStatusListSize::get().saturating_mul(8);
+		let updates: Vec<(u32, bool)> = (0..u.min(T::MaxStatusUpdates::get()))
+			.map(|i| (i.saturating_mul(capacity / 256) % capacity, true))
+			.collect();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), 0, updates);
+
+		if u > 0 {
+			assert!(AuraCredentials::<T>::credential_status(&did, 0, 0).map_or(false, |s| s.status));
+		}
+	}
+
+	#[benchmark]
+	fn remove_status_list() {
+		let (who, did) = issuer::<T>();
+		status_list::<T>(&who, StatusPurpose::Revocation);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), T::DidLookup::unlookup(did), 0);
+
+		assert!(!StatusLists::<T>::contains_key(did, 0));
+	}
+
+	// Худший случай: о субъекте уже `MaxClaimsPerSubject - 1` утверждений
+	#[benchmark]
+	fn attest_claim() {
+		let (who, issuer) = identity::<T>(0);
+		let (_, subject) = identity::<T>(1);
+		for index in 1..T::MaxClaimsPerSubject::get() {
+			let (other, other_did) = identity::<T>(index + 1);
+			attested_claim::<T>(&other, &other_did, subject);
+		}
+		let claim_type = [1u8; 32];
+		let claim_id = AuraCredentials::<T>::claim_id(&issuer, &subject, &claim_type);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), T::DidLookup::unlookup(subject), claim_type, None, Some([2u8; 32]));
+
+		assert_last_event::<T>(Event::ClaimAttested { claim_id, issuer, subject, claim_type }.into());
+	}
+
+	#[benchmark]
+	fn revoke_claim() {
+		let (who, issuer) = identity::<T>(0);
+		let (_, subject) = identity::<T>(1);
+		let claim_id = attested_claim::<T>(&who, &issuer, subject);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), T::DidLookup::unlookup(issuer), claim_id);
+
+		assert!(!Claims::<T>::contains_key(subject, claim_id));
+	}
+
+	#[benchmark]
+	fn set_claim_visibility() {
+		let (who, issuer) = identity::<T>(0);
+		let (subject_account, subject) = identity::<T>(1);
+		let claim_id = attested_claim::<T>(&who, &issuer, subject);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(subject_account), claim_id, true);
+
+		assert!(Claims::<T>::get(subject, claim_id).map_or(false, |claim| claim.hidden));
+	}
+
+	#[benchmark]
+	fn reject_claim() {
+		let (who, issuer) = identity::<T>(0);
+		let (subject_account, subject) = identity::<T>(1);
+		let claim_id = attested_claim::<T>(&who, &issuer, subject);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(subject_account), claim_id);
+
+		assert_last_event::<T>(Event::ClaimRejected { claim_id, subject }.into());
+	}
+
+	impl_benchmark_test_suite!(AuraCredentials, crate::tests::new_test_ext(), crate::tests::Test);
+}
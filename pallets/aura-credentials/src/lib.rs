@@ -0,0 +1,1175 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Реестр издателей верифицируемых учетных данных (W3C Verifiable Credentials).
+//!
+//! Aura ID регистрируется издателем по своему DID, публикует схемы учетных
+//! данных по хешу и ведет списки статусов в формате W3C Bitstring Status List:
+//! бит `index` списка `list_id` издателя - статус выданного документа
+//! (отозван или приостановлен). Сами учетные данные в цепочку не попадают.
+//!
+//! Кроме того, любой Aura ID может заявить факт о другом Aura ID (claim):
+//! хеш типа утверждения, срок действия и необязательный хеш содержимого.
+//! Утверждения индексируются по субъекту и по издателю; субъект может скрыть
+//! нежелательные утверждения из своего DID документа.
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ReservableCurrency},
+		Blake2_128Concat, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_aura_identity::{AuraIdentities, DidIndex, DidLookupOf, Tombstones};
+	use sp_runtime::traits::{Saturating, StaticLookup};
+	use sp_std::vec::Vec;
+	use scale_info::TypeInfo;
+	use crate::weights::WeightInfo;
+
+	/// Максимальная длина URI схемы учетных данных
+	pub const MAX_SCHEMA_URI_LENGTH: u32 = 256;
+
+	/// Максимальный размер страницы в `claims_issued_by`
+	pub const MAX_CLAIMS_PAGE: u32 = 256;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_aura_identity::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Депозит за регистрацию издателя
+		#[pallet::constant]
+		type IssuerDeposit: Get<BalanceOf<Self>>;
+
+		/// Депозит за публикацию схемы
+		#[pallet::constant]
+		type SchemaDeposit: Get<BalanceOf<Self>>;
+
+		/// Депозит за каждый байт списка статусов
+		#[pallet::constant]
+		type StatusListByteDeposit: Get<BalanceOf<Self>>;
+
+		/// Максимальный размер списка статусов в байтах (8 статусов на байт)
+		#[pallet::constant]
+		type MaxStatusListSize: Get<u32>;
+
+		/// Максимальное количество списков статусов у издателя
+		#[pallet::constant]
+		type MaxStatusLists: Get<u32>;
+
+		/// Максимальное количество изменений статусов в одном вызове
+		#[pallet::constant]
+		type MaxStatusUpdates: Get<u32>;
+
+		/// Депозит за утверждение о другом Aura ID
+		#[pallet::constant]
+		type ClaimDeposit: Get<BalanceOf<Self>>;
+
+		/// Максимальное количество утверждений об одном DID. Ограничивает
+		/// `claims_of` и раздел утверждений в DID документе. Скрытые
+		/// утверждения тоже учитываются; место освобождает `reject_claim`.
+		#[pallet::constant]
+		type MaxClaimsPerSubject: Get<u32>;
+
+		/// Веса экстринсиков паллета
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Депозиты берутся в валюте паллета Aura Identity
+	pub type BalanceOf<T> = <<T as pallet_aura_identity::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	// ========== СТРУКТУРЫ ==========
+
+	/// Зарегистрированный издатель
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Issuer<AccountId, Balance, BlockNumber> {
+		/// Аккаунт, которому принадлежит DID издателя
+		pub account: AccountId,
+		/// Депозит за регистрацию
+		pub deposit: Balance,
+		/// Блок регистрации
+		pub registered: BlockNumber,
+	}
+
+	/// Схема учетных данных, опубликованная издателем
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct CredentialSchema<Balance, BlockNumber> {
+		/// DID издателя
+		pub issuer: [u8; 32],
+		/// Где опубликован документ схемы
+		pub uri: BoundedVec<u8, ConstU32<MAX_SCHEMA_URI_LENGTH>>,
+		/// Депозит за хранение
+		pub deposit: Balance,
+		/// Блок публикации
+		pub created: BlockNumber,
+	}
+
+	/// Назначение списка статусов (`statusPurpose` в W3C Bitstring Status List)
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum StatusPurpose {
+		/// Установленный бит - документ отозван навсегда
+		Revocation,
+		/// Установленный бит - документ приостановлен, бит можно снять
+		Suspension,
+	}
+
+	/// Список статусов издателя
+	#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct StatusList<T: Config> {
+		pub purpose: StatusPurpose,
+		/// Битовая строка: статус с индексом `i` - бит `7 - i % 8` байта `i / 8`
+		pub bits: BoundedVec<u8, T::MaxStatusListSize>,
+		/// Депозит за хранение
+		pub deposit: BalanceOf<T>,
+		/// Блок последнего изменения
+		pub updated: BlockNumberFor<T>,
+	}
+
+	/// Статус учетных данных для верификатора
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct CredentialStatus<BlockNumber> {
+		pub purpose: StatusPurpose,
+		/// Бит установлен: документ отозван или приостановлен
+		pub status: bool,
+		/// Блок последнего изменения списка
+		pub updated: BlockNumber,
+		/// DID издателя не деактивирован
+		pub issuer_active: bool,
+	}
+
+	/// Утверждение одного DID о другом
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Claim<AccountId, Balance, BlockNumber> {
+		/// DID издателя утверждения
+		pub issuer: [u8; 32],
+		/// Хеш типа утверждения (например, "email verified")
+		pub claim_type: [u8; 32],
+		/// Хеш содержимого, хранящегося вне цепочки
+		pub payload_hash: Option<[u8; 32]>,
+		/// Последний блок, в котором утверждение действительно
+		pub expiry: Option<BlockNumber>,
+		/// Блок выдачи
+		pub issued: BlockNumber,
+		/// Субъект скрыл утверждение из своего DID документа
+		pub hidden: bool,
+		/// Аккаунт, зарезервировавший депозит
+		pub depositor: AccountId,
+		pub deposit: Balance,
+	}
+
+	// ========== STORAGE ==========
+
+	#[pallet::storage]
+	#[pallet::getter(fn issuer)]
+	/// Издатели по DID
+	pub type Issuers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		[u8; 32],
+		Issuer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn schema)]
+	/// Схемы учетных данных по хешу документа схемы
+	pub type Schemas<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		[u8; 32],
+		CredentialSchema<BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	/// Схемы издателя: DID издателя -> хеш схемы
+	pub type IssuerSchemas<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		[u8; 32],
+		Blake2_128Concat,
+		[u8; 32],
+		(),
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn status_list)]
+	/// Списки статусов: DID издателя -> номер списка -> список
+	pub type StatusLists<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		[u8; 32],
+		Blake2_128Concat,
+		u32,
+		StatusList<T>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_status_list_id)]
+	/// Номер следующего списка статусов издателя
+	pub type NextStatusListId<T: Config> = StorageMap<_, Blake2_128Concat, [u8; 32], u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claim)]
+	/// Утверждения: DID субъекта -> идентификатор утверждения -> утверждение
+	pub type Claims<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		[u8; 32],
+		Blake2_128Concat,
+		[u8; 32],
+		Claim<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claim_subject)]
+	/// Индекс утверждений по издателю: DID издателя -> идентификатор -> DID субъекта
+	pub type ClaimsByIssuer<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		[u8; 32],
+		Blake2_128Concat,
+		[u8; 32],
+		[u8; 32],
+		OptionQuery
+	>;
+
+	// ========== СОБЫТИЯ ==========
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		IssuerRegistered { did: [u8; 32], account: T::AccountId },
+		IssuerUnregistered { did: [u8; 32] },
+		SchemaPublished { schema_hash: [u8; 32], issuer: [u8; 32] },
+		SchemaRemoved { schema_hash: [u8; 32] },
+		StatusListCreated {
+			issuer: [u8; 32],
+			list_id: u32,
+			purpose: StatusPurpose,
+			size: u32
+		},
+		StatusUpdated { issuer: [u8; 32], list_id: u32, updates: u32 },
+		StatusListRemoved { issuer: [u8; 32], list_id: u32 },
+		ClaimAttested {
+			claim_id: [u8; 32],
+			issuer: [u8; 32],
+			subject: [u8; 32],
+			claim_type: [u8; 32]
+		},
+		ClaimRevoked { claim_id: [u8; 32], subject: [u8; 32] },
+		ClaimVisibilityChanged { claim_id: [u8; 32], hidden: bool },
+		ClaimRejected { claim_id: [u8; 32], subject: [u8; 32] },
+	}
+
+	// ========== ОШИБКИ ==========
+
+	#[pallet::error]
+	pub enum Error<T> {
+		AuraIdNotFound,
+		IssuerAlreadyRegistered,
+		IssuerNotFound,
+		IssuerHasStatusLists,
+		IssuerHasSchemas,
+		SchemaAlreadyExists,
+		SchemaNotFound,
+		InvalidSchemaUri,
+		NotSchemaIssuer,
+		StatusListNotFound,
+		InvalidStatusListSize,
+		TooManyStatusLists,
+		TooManyStatusUpdates,
+		StatusIndexOutOfBounds,
+		RevocationIsPermanent,
+		SubjectNotFound,
+		SelfAttestation,
+		ClaimAlreadyExists,
+		ClaimNotFound,
+		InvalidClaimExpiry,
+		TooManyClaims,
+		NotClaimIssuer,
+	}
+
+	// ========== CALL ФУНКЦИИ ==========
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		// Регистрация DID вызывающего аккаунта издателем
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_issuer())]
+		pub fn register_issuer(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let did = AuraIdentities::<T>::get(&who).ok_or(Error::<T>::AuraIdNotFound)?.did;
+			ensure!(!Issuers::<T>::contains_key(did), Error::<T>::IssuerAlreadyRegistered);
+
+			let deposit = T::IssuerDeposit::get();
+			<T as pallet_aura_identity::Config>::Currency::reserve(&who, deposit)?;
+
+			Issuers::<T>::insert(did, Issuer {
+				account: who.clone(),
+				deposit,
+				registered: frame_system::Pallet::<T>::block_number(),
+			});
+
+			Self::deposit_event(Event::IssuerRegistered { did, account: who });
+
+			Ok(())
+		}
+
+		// Снятие регистрации издателя. Списки статусов нужно удалить заранее:
+		// верификаторы не должны внезапно потерять статусы выданных документов.
+		// DID издателя указывается явно: после деактивации DID у аккаунта нет
+		// Aura ID, но депозит по-прежнему можно вернуть.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::unregister_issuer())]
+		pub fn unregister_issuer(origin: OriginFor<T>, issuer: DidLookupOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let did = T::DidLookup::lookup(issuer)?;
+
+			let issuer = Self::ensure_issuer_account(&who, &did)?;
+			ensure!(
+				StatusLists::<T>::iter_key_prefix(did).next().is_none(),
+				Error::<T>::IssuerHasStatusLists
+			);
+			// Схемы удаляются через издателя, иначе их депозиты нельзя вернуть
+			ensure!(
+				IssuerSchemas::<T>::iter_key_prefix(did).next().is_none(),
+				Error::<T>::IssuerHasSchemas
+			);
+
+			Issuers::<T>::remove(did);
+			<T as pallet_aura_identity::Config>::Currency::unreserve(&who, issuer.deposit);
+
+			Self::deposit_event(Event::IssuerUnregistered { did });
+
+			Ok(())
+		}
+
+		// Публикация схемы учетных данных по хешу ее документа
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::publish_schema())]
+		pub fn publish_schema(
+			origin: OriginFor<T>,
+			schema_hash: [u8; 32],
+			uri: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (did, _) = Self::ensure_issuer(&who)?;
+			ensure!(!Schemas::<T>::contains_key(schema_hash), Error::<T>::SchemaAlreadyExists);
+			ensure!(!uri.is_empty(), Error::<T>::InvalidSchemaUri);
+			let uri: BoundedVec<u8, ConstU32<MAX_SCHEMA_URI_LENGTH>> = uri
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidSchemaUri)?;
+
+			let deposit = T::SchemaDeposit::get();
+			<T as pallet_aura_identity::Config>::Currency::reserve(&who, deposit)?;
+
+			Schemas::<T>::insert(schema_hash, CredentialSchema {
+				issuer: did,
+				uri,
+				deposit,
+				created: frame_system::Pallet::<T>::block_number(),
+			});
+			IssuerSchemas::<T>::insert(did, schema_hash, ());
+
+			Self::deposit_event(Event::SchemaPublished { schema_hash, issuer: did });
+
+			Ok(())
+		}
+
+		// Удаление схемы издателем, в том числе после деактивации его DID
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_schema())]
+		pub fn remove_schema(origin: OriginFor<T>, schema_hash: [u8; 32]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schema = Schemas::<T>::get(schema_hash).ok_or(Error::<T>::SchemaNotFound)?;
+			let issuer = Issuers::<T>::get(schema.issuer).ok_or(Error::<T>::IssuerNotFound)?;
+			ensure!(issuer.account == who, Error::<T>::NotSchemaIssuer);
+
+			Schemas::<T>::remove(schema_hash);
+			IssuerSchemas::<T>::remove(schema.issuer, schema_hash);
+			<T as pallet_aura_identity::Config>::Currency::unreserve(&who, schema.deposit);
+
+			Self::deposit_event(Event::SchemaRemoved { schema_hash });
+
+			Ok(())
+		}
+
+		// Создание пустого списка статусов размером `size` байт
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_status_list(*size))]
+		pub fn create_status_list(
+			origin: OriginFor<T>,
+			purpose: StatusPurpose,
+			size: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (did, _) = Self::ensure_issuer(&who)?;
+			ensure!(
+				size > 0 && size <= T::MaxStatusListSize::get(),
+				Error::<T>::InvalidStatusListSize
+			);
+			ensure!(
+				(StatusLists::<T>::iter_key_prefix(did).count() as u32) < T::MaxStatusLists::get(),
+				Error::<T>::TooManyStatusLists
+			);
+
+			let deposit = T::StatusListByteDeposit::get().saturating_mul(size.into());
+			<T as pallet_aura_identity::Config>::Currency::reserve(&who, deposit)?;
+
+			let bits: BoundedVec<u8, T::MaxStatusListSize> = sp_std::vec![0u8; size as usize]
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidStatusListSize)?;
+			let list_id = NextStatusListId::<T>::get(did);
+			NextStatusListId::<T>::insert(did, list_id.saturating_add(1));
+			StatusLists::<T>::insert(did, list_id, StatusList {
+				purpose,
+				bits,
+				deposit,
+				updated: frame_system::Pallet::<T>::block_number(),
+			});
+
+			Self::deposit_event(Event::StatusListCreated { issuer: did, list_id, purpose, size });
+
+			Ok(())
+		}
+
+		// Изменение статусов: `(индекс, статус)`. Отзыв необратим, приостановку
+		// можно снять.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_status(updates.len() as u32))]
+		pub fn update_status(
+			origin: OriginFor<T>,
+			list_id: u32,
+			updates: Vec<(u32, bool)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (did, _) = Self::ensure_issuer(&who)?;
+			ensure!(
+				updates.len() as u32 <= T::MaxStatusUpdates::get(),
+				Error::<T>::TooManyStatusUpdates
+			);
+
+			StatusLists::<T>::try_mutate(did, list_id, |maybe_list| {
+				let list = maybe_list.as_mut().ok_or(Error::<T>::StatusListNotFound)?;
+				for (index, status) in &updates {
+					let byte = list
+						.bits
+						.get_mut((*index / 8) as usize)
+						.ok_or(Error::<T>::StatusIndexOutOfBounds)?;
+					let mask = 0x80u8 >> (*index % 8);
+					if *status {
+						*byte |= mask;
+					} else {
+						ensure!(
+							list.purpose != StatusPurpose::Revocation || *byte & mask == 0,
+							Error::<T>::RevocationIsPermanent
+						);
+						*byte &= !mask;
+					}
+				}
+				list.updated = frame_system::Pallet::<T>::block_number();
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::StatusUpdated {
+				issuer: did,
+				list_id,
+				updates: updates.len() as u32
+			});
+
+			Ok(())
+		}
+
+		// Удаление списка статусов с возвратом депозита, в том числе после
+		// деактивации DID издателя
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_status_list())]
+		pub fn remove_status_list(
+			origin: OriginFor<T>,
+			issuer: DidLookupOf<T>,
+			list_id: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let did = T::DidLookup::lookup(issuer)?;
+
+			Self::ensure_issuer_account(&who, &did)?;
+			let list = StatusLists::<T>::take(did, list_id).ok_or(Error::<T>::StatusListNotFound)?;
+			<T as pallet_aura_identity::Config>::Currency::unreserve(&who, list.deposit);
+
+			Self::deposit_event(Event::StatusListRemoved { issuer: did, list_id });
+
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - утверждение о другом Aura ID. Идентификатор утверждения
+		// выводится из издателя, субъекта и типа (см. `claim_id`), поэтому
+		// повторное утверждение того же типа требует отзыва предыдущего.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::attest_claim())]
+		pub fn attest_claim(
+			origin: OriginFor<T>,
+			subject: DidLookupOf<T>,
+			claim_type: [u8; 32],
+			expiry: Option<BlockNumberFor<T>>,
+			payload_hash: Option<[u8; 32]>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let subject = T::DidLookup::lookup(subject)?;
+
+			let issuer = AuraIdentities::<T>::get(&who).ok_or(Error::<T>::AuraIdNotFound)?.did;
+			ensure!(subject != issuer, Error::<T>::SelfAttestation);
+			ensure!(
+				DidIndex::<T>::contains_key(subject) && !Tombstones::<T>::contains_key(subject),
+				Error::<T>::SubjectNotFound
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Some(expiry) = expiry {
+				ensure!(expiry > now, Error::<T>::InvalidClaimExpiry);
+			}
+
+			let claim_id = Self::claim_id(&issuer, &subject, &claim_type);
+			ensure!(!Claims::<T>::contains_key(subject, claim_id), Error::<T>::ClaimAlreadyExists);
+			ensure!(
+				(Claims::<T>::iter_key_prefix(subject).count() as u32) < T::MaxClaimsPerSubject::get(),
+				Error::<T>::TooManyClaims
+			);
+
+			let deposit = T::ClaimDeposit::get();
+			<T as pallet_aura_identity::Config>::Currency::reserve(&who, deposit)?;
+
+			Claims::<T>::insert(subject, claim_id, Claim {
+				issuer,
+				claim_type,
+				payload_hash,
+				expiry,
+				issued: now,
+				hidden: false,
+				depositor: who,
+				deposit,
+			});
+			ClaimsByIssuer::<T>::insert(issuer, claim_id, subject);
+
+			Self::deposit_event(Event::ClaimAttested { claim_id, issuer, subject, claim_type });
+
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - отзыв утверждения его издателем. Утверждение ищется по
+		// индексу издателя, а право на отзыв проверяется по аккаунту, внесшему
+		// депозит: так издатель может отозвать утверждение и после смены ключа
+		// или деактивации своего DID.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_claim())]
+		pub fn revoke_claim(
+			origin: OriginFor<T>,
+			issuer: DidLookupOf<T>,
+			claim_id: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let issuer = T::DidLookup::lookup(issuer)?;
+
+			let subject = ClaimsByIssuer::<T>::get(issuer, claim_id).ok_or(Error::<T>::ClaimNotFound)?;
+			let claim = Claims::<T>::get(subject, claim_id).ok_or(Error::<T>::ClaimNotFound)?;
+			ensure!(claim.depositor == who, Error::<T>::NotClaimIssuer);
+
+			ClaimsByIssuer::<T>::remove(issuer, claim_id);
+			Claims::<T>::remove(subject, claim_id);
+			<T as pallet_aura_identity::Config>::Currency::unreserve(&claim.depositor, claim.deposit);
+
+			Self::deposit_event(Event::ClaimRevoked { claim_id, subject });
+
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - субъект скрывает утверждение из своего DID документа
+		// или возвращает его. Утверждение остается в цепочке и доступно по запросу.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_claim_visibility())]
+		pub fn set_claim_visibility(
+			origin: OriginFor<T>,
+			claim_id: [u8; 32],
+			hidden: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let subject = AuraIdentities::<T>::get(&who).ok_or(Error::<T>::AuraIdNotFound)?.did;
+			Claims::<T>::try_mutate(subject, claim_id, |maybe_claim| {
+				let claim = maybe_claim.as_mut().ok_or(Error::<T>::ClaimNotFound)?;
+				claim.hidden = hidden;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::ClaimVisibilityChanged { claim_id, hidden });
+
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - субъект отклоняет утверждение о себе. Утверждение
+		// удаляется, а депозит возвращается издателю. Скрытые утверждения
+		// тоже занимают место в `MaxClaimsPerSubject`, поэтому нежелательные
+		// утверждения нужно отклонять, а не скрывать.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::reject_claim())]
+		pub fn reject_claim(origin: OriginFor<T>, claim_id: [u8; 32]) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let subject = AuraIdentities::<T>::get(&who).ok_or(Error::<T>::AuraIdNotFound)?.did;
+			let claim = Claims::<T>::take(subject, claim_id).ok_or(Error::<T>::ClaimNotFound)?;
+			ClaimsByIssuer::<T>::remove(claim.issuer, claim_id);
+			<T as pallet_aura_identity::Config>::Currency::unreserve(&claim.depositor, claim.deposit);
+
+			Self::deposit_event(Event::ClaimRejected { claim_id, subject });
+
+			Ok(())
+		}
+	}
+
+	// ========== ВСПОМОГАТЕЛЬНЫЕ ФУНКЦИИ ==========
+
+	impl<T: Config> Pallet<T> {
+		/// DID и запись издателя, от имени которого действует аккаунт.
+		/// Требует действующего Aura ID: деактивированный издатель не может
+		/// публиковать схемы и менять статусы.
+		fn ensure_issuer(
+			who: &T::AccountId,
+		) -> Result<([u8; 32], Issuer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>), DispatchError> {
+			let did = AuraIdentities::<T>::get(who).ok_or(Error::<T>::AuraIdNotFound)?.did;
+			let issuer = Self::ensure_issuer_account(who, &did)?;
+			Ok((did, issuer))
+		}
+
+		/// Запись издателя `did`, зарегистрированного аккаунтом `who`. Проверяет
+		/// сохраненный `Issuer.account`, а не текущий Aura ID аккаунта, поэтому
+		/// подходит для удаления данных издателя после деактивации его DID.
+		fn ensure_issuer_account(
+			who: &T::AccountId,
+			did: &[u8; 32],
+		) -> Result<Issuer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>, DispatchError> {
+			let issuer = Issuers::<T>::get(did).ok_or(Error::<T>::IssuerNotFound)?;
+			ensure!(issuer.account == *who, Error::<T>::IssuerNotFound);
+			Ok(issuer)
+		}
+
+		/// Статус учетных данных с индексом `index` в списке `list_id` издателя.
+		/// `None`, если списка нет или индекс за его пределами.
+		pub fn credential_status(
+			issuer: &[u8; 32],
+			list_id: u32,
+			index: u32,
+		) -> Option<CredentialStatus<BlockNumberFor<T>>> {
+			let list = StatusLists::<T>::get(issuer, list_id)?;
+			let byte = *list.bits.get((index / 8) as usize)?;
+
+			Some(CredentialStatus {
+				purpose: list.purpose,
+				status: byte & (0x80u8 >> (index % 8)) != 0,
+				updated: list.updated,
+				issuer_active: Issuers::<T>::contains_key(issuer) && !Tombstones::<T>::contains_key(issuer),
+			})
+		}
+
+		/// Идентификатор утверждения издателя `issuer` типа `claim_type` о `subject`
+		pub fn claim_id(issuer: &[u8; 32], subject: &[u8; 32], claim_type: &[u8; 32]) -> [u8; 32] {
+			sp_io::hashing::blake2_256(&(b"aura/claim", issuer, subject, claim_type).encode())
+		}
+
+		/// Утверждение действительно: срок не истек и DID издателя не деактивирован
+		pub fn is_claim_valid(claim: &Claim<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			claim.expiry.map_or(true, |expiry| now <= expiry) && !Tombstones::<T>::contains_key(claim.issuer)
+		}
+
+		/// Утверждения о субъекте; скрытые - только при `include_hidden`.
+		/// Не больше `MaxClaimsPerSubject`.
+		pub fn claims_of(
+			subject: &[u8; 32],
+			include_hidden: bool,
+		) -> Vec<([u8; 32], Claim<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>)> {
+			Claims::<T>::iter_prefix(subject)
+				.filter(|(_, claim)| include_hidden || !claim.hidden)
+				.collect()
+		}
+
+		/// Страница утверждений издателя: идентификатор и DID субъекта. Страница
+		/// начинается после утверждения `start_after` и содержит не больше
+		/// `limit` (и не больше `MAX_CLAIMS_PAGE`) записей. Порядок определяется
+		/// хешами ключей и не меняется между блоками, пока записи не удалены.
+		pub fn claims_issued_by(
+			issuer: &[u8; 32],
+			start_after: Option<[u8; 32]>,
+			limit: u32,
+		) -> Vec<([u8; 32], [u8; 32])> {
+			let claims = match start_after {
+				Some(claim_id) => ClaimsByIssuer::<T>::iter_prefix_from(
+					issuer,
+					ClaimsByIssuer::<T>::hashed_key_for(issuer, claim_id),
+				),
+				None => ClaimsByIssuer::<T>::iter_prefix(issuer),
+			};
+			claims.take(limit.min(MAX_CLAIMS_PAGE) as usize).collect()
+		}
+	}
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+	use frame_support::{
+		assert_noop, assert_ok,
+		traits::{ConstU128, ConstU32, ReservableCurrency},
+		BoundedVec,
+	};
+	use sp_core::H256;
+	use sp_runtime::{
+		traits::{BlakeTwo256, IdentityLookup},
+		BuildStorage,
+	};
+
+	type Block = frame_system::mocking::MockBlock<Test>;
+
+	frame_support::construct_runtime!(
+		pub enum Test {
+			System: frame_system,
+			Balances: pallet_balances,
+			AuraIdentity: pallet_aura_identity,
+			AuraCredentials: pallet,
+		}
+	);
+
+	frame_support::parameter_types! {
+		pub TestSlashBeneficiary: Option<u64> = None;
+		pub const TestRecoveryBondSlash: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	}
+
+	impl frame_system::Config for Test {
+		type BaseCallFilter = frame_support::traits::Everything;
+		type BlockWeights = ();
+		type BlockLength = ();
+		type DbWeight = ();
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeCall = RuntimeCall;
+		type Nonce = u64;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type Block = Block;
+		type RuntimeEvent = RuntimeEvent;
+		type BlockHashCount = frame_support::traits::ConstU64<250>;
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<u128>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ();
+		type OnSetCode = ();
+		type MaxConsumers = frame_support::traits::ConstU32<16>;
+	}
+
+	impl pallet_balances::Config for Test {
+		type Balance = u128;
+		type RuntimeEvent = RuntimeEvent;
+		type DustRemoval = ();
+		type ExistentialDeposit = ConstU128<1>;
+		type AccountStore = System;
+		type WeightInfo = ();
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type HoldIdentifier = ();
+		type FreezeIdentifier = ();
+		type MaxHolds = ();
+		type MaxFreezes = ();
+	}
+
+	impl pallet_aura_identity::Config for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type Currency = Balances;
+		type MaxTrustees = ConstU32<10>;
+		type MaxTrusteeships = ConstU32<16>;
+		type RecoveryDeposit = ConstU128<100>;
+		type RecoveryBond = ConstU128<50>;
+		type RecoveryBondSlash = TestRecoveryBondSlash;
+		type SlashBeneficiary = TestSlashBeneficiary;
+		type DefaultRecoveryDelay = ConstU32<100>;
+		type MinRecoveryDelay = ConstU32<10>;
+		type MaxRecoveryDelay = ConstU32<1_000>;
+		type RecoveryLifetime = ConstU32<50>;
+		type ShareComplaintPeriod = ConstU32<20>;
+		type MaxKeyHistory = ConstU32<2>;
+		type MaxVerificationMethods = ConstU32<2>;
+		type MaxServices = ConstU32<2>;
+		type MaxControllers = ConstU32<2>;
+		type DocumentDepositBase = ConstU128<10>;
+		type DocumentByteDeposit = ConstU128<1>;
+		type ForceOrigin = frame_system::EnsureRoot<u64>;
+		type DidLookup = IdentityLookup<[u8; 32]>;
+		type TrusteeLookup = IdentityLookup<Self::AccountId>;
+		type WeightInfo = ();
+	}
+
+	impl Config for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type IssuerDeposit = ConstU128<100>;
+		type SchemaDeposit = ConstU128<20>;
+		type StatusListByteDeposit = ConstU128<1>;
+		type MaxStatusListSize = ConstU32<16>;
+		type MaxStatusLists = ConstU32<2>;
+		type MaxStatusUpdates = ConstU32<4>;
+		type ClaimDeposit = ConstU128<10>;
+		type MaxClaimsPerSubject = ConstU32<2>;
+		type WeightInfo = ();
+	}
+
+	// Aura ID с DID `[account; 32]` без доказательства владения ключом
+	pub(crate) fn insert_aura_id(account: u64) -> [u8; 32] {
+		let did = [account as u8; 32];
+		pallet_aura_identity::AuraIdentities::<Test>::insert(account, pallet_aura_identity::AuraIdRecord {
+			did,
+			public_key: pallet_aura_identity::AuraPublicKey::Sr25519([account as u8; 32]),
+			recovery_config: BoundedVec::default(),
+			created: Some(1),
+		});
+		pallet_aura_identity::DidIndex::<Test>::insert(did, account);
+		did
+	}
+
+	// Издатель (1) со списком отзыва из 2 байт (16 статусов)
+	fn setup_issuer_with_list() -> [u8; 32] {
+		let did = insert_aura_id(1);
+		assert_ok!(AuraCredentials::register_issuer(RuntimeOrigin::signed(1)));
+		assert_ok!(AuraCredentials::create_status_list(RuntimeOrigin::signed(1), StatusPurpose::Revocation, 2));
+		did
+	}
+
+	#[test]
+	fn test_register_issuer_and_publish_schema() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AuraCredentials::register_issuer(RuntimeOrigin::signed(1)),
+				Error::<Test>::AuraIdNotFound
+			);
+
+			let did = insert_aura_id(1);
+			assert_ok!(AuraCredentials::register_issuer(RuntimeOrigin::signed(1)));
+			assert_eq!(AuraCredentials::issuer(did).unwrap().account, 1);
+			assert_eq!(Balances::reserved_balance(1), 100);
+			assert_noop!(
+				AuraCredentials::register_issuer(RuntimeOrigin::signed(1)),
+				Error::<Test>::IssuerAlreadyRegistered
+			);
+
+			let schema_hash = [7u8; 32];
+			assert_ok!(AuraCredentials::publish_schema(
+				RuntimeOrigin::signed(1),
+				schema_hash,
+				b"https://schemas.example.com/kyc.json".to_vec(),
+			));
+			assert_eq!(AuraCredentials::schema(schema_hash).unwrap().issuer, did);
+			System::assert_last_event(Event::SchemaPublished { schema_hash, issuer: did }.into());
+
+			// Не издатель не может публиковать и удалять схемы
+			insert_aura_id(2);
+			assert_noop!(
+				AuraCredentials::publish_schema(RuntimeOrigin::signed(2), [8u8; 32], b"uri".to_vec()),
+				Error::<Test>::IssuerNotFound
+			);
+			assert_ok!(AuraCredentials::register_issuer(RuntimeOrigin::signed(2)));
+			assert_noop!(
+				AuraCredentials::remove_schema(RuntimeOrigin::signed(2), schema_hash),
+				Error::<Test>::NotSchemaIssuer
+			);
+
+			assert_noop!(
+				AuraCredentials::unregister_issuer(RuntimeOrigin::signed(2), did),
+				Error::<Test>::IssuerNotFound
+			);
+			assert_ok!(AuraCredentials::remove_schema(RuntimeOrigin::signed(1), schema_hash));
+			assert_ok!(AuraCredentials::unregister_issuer(RuntimeOrigin::signed(1), did));
+			assert_eq!(Balances::reserved_balance(1), 0);
+		});
+	}
+
+	#[test]
+	fn test_unregister_issuer_with_schema() {
+		new_test_ext().execute_with(|| {
+			let did = insert_aura_id(1);
+			assert_ok!(AuraCredentials::register_issuer(RuntimeOrigin::signed(1)));
+			let schema_hash = [7u8; 32];
+			assert_ok!(AuraCredentials::publish_schema(RuntimeOrigin::signed(1), schema_hash, b"uri".to_vec()));
+			let reserved = Balances::reserved_balance(1);
+
+			// Без издателя схему нельзя было бы удалить и вернуть депозит
+			assert_noop!(
+				AuraCredentials::unregister_issuer(RuntimeOrigin::signed(1), did),
+				Error::<Test>::IssuerHasSchemas
+			);
+
+			assert_ok!(AuraCredentials::remove_schema(RuntimeOrigin::signed(1), schema_hash));
+			assert!(IssuerSchemas::<Test>::iter_key_prefix(did).next().is_none());
+			assert!(Balances::reserved_balance(1) < reserved);
+			assert_ok!(AuraCredentials::unregister_issuer(RuntimeOrigin::signed(1), did));
+			assert_eq!(Balances::reserved_balance(1), 0);
+		});
+	}
+
+	#[test]
+	fn test_status_list_updates() {
+		new_test_ext().execute_with(|| {
+			let did = setup_issuer_with_list();
+			assert_eq!(Balances::reserved_balance(1), 102);
+			assert!(!AuraCredentials::credential_status(&did, 0, 9).unwrap().status);
+
+			System::set_block_number(5);
+			assert_ok!(AuraCredentials::update_status(RuntimeOrigin::signed(1), 0, vec![(0, true), (9, true)]));
+
+			// Бит 0 - старший бит первого байта
+			assert_eq!(AuraCredentials::status_list(did, 0).unwrap().bits.into_inner(), vec![0x80, 0x40]);
+			let status = AuraCredentials::credential_status(&did, 0, 9).unwrap();
+			assert!(status.status);
+			assert_eq!(status.updated, 5);
+			assert!(status.issuer_active);
+
+			// Отзыв необратим
+			assert_noop!(
+				AuraCredentials::update_status(RuntimeOrigin::signed(1), 0, vec![(9, false)]),
+				Error::<Test>::RevocationIsPermanent
+			);
+			assert_noop!(
+				AuraCredentials::update_status(RuntimeOrigin::signed(1), 0, vec![(16, true)]),
+				Error::<Test>::StatusIndexOutOfBounds
+			);
+			assert!(AuraCredentials::credential_status(&did, 0, 16).is_none());
+
+			// Приостановку можно снять
+			assert_ok!(AuraCredentials::create_status_list(RuntimeOrigin::signed(1), StatusPurpose::Suspension, 1));
+			assert_ok!(AuraCredentials::update_status(RuntimeOrigin::signed(1), 1, vec![(3, true)]));
+			assert_ok!(AuraCredentials::update_status(RuntimeOrigin::signed(1), 1, vec![(3, false)]));
+			assert!(!AuraCredentials::credential_status(&did, 1, 3).unwrap().status);
+
+			assert_noop!(
+				AuraCredentials::create_status_list(RuntimeOrigin::signed(1), StatusPurpose::Revocation, 1),
+				Error::<Test>::TooManyStatusLists
+			);
+			assert_noop!(
+				AuraCredentials::unregister_issuer(RuntimeOrigin::signed(1), did),
+				Error::<Test>::IssuerHasStatusLists
+			);
+		});
+	}
+
+	#[test]
+	fn test_status_of_deactivated_issuer() {
+		new_test_ext().execute_with(|| {
+			let did = setup_issuer_with_list();
+			assert_ok!(AuraCredentials::update_status(RuntimeOrigin::signed(1), 0, vec![(2, true)]));
+			assert_ok!(AuraCredentials::publish_schema(RuntimeOrigin::signed(1), [7u8; 32], b"uri".to_vec()));
+
+			assert_ok!(AuraIdentity::force_deactivate_aura_id(RuntimeOrigin::root(), 1));
+
+			// Статусы остаются доступны верификаторам, но издатель помечен неактивным
+			let status = AuraCredentials::credential_status(&did, 0, 2).unwrap();
+			assert!(status.status);
+			assert!(!status.issuer_active);
+			assert_noop!(
+				AuraCredentials::update_status(RuntimeOrigin::signed(1), 0, vec![(3, true)]),
+				Error::<Test>::AuraIdNotFound
+			);
+
+			// Списки, схемы и регистрацию можно удалить и вернуть депозиты
+			// и после деактивации
+			assert_ok!(AuraCredentials::remove_status_list(RuntimeOrigin::signed(1), did, 0));
+			assert_ok!(AuraCredentials::remove_schema(RuntimeOrigin::signed(1), [7u8; 32]));
+			assert_ok!(AuraCredentials::unregister_issuer(RuntimeOrigin::signed(1), did));
+			assert_eq!(Balances::reserved_balance(1), 0);
+		});
+	}
+
+	#[test]
+	fn test_attest_and_revoke_claim() {
+		new_test_ext().execute_with(|| {
+			let issuer = insert_aura_id(1);
+			let subject = insert_aura_id(2);
+			let claim_type = [9u8; 32];
+
+			assert_noop!(
+				AuraCredentials::attest_claim(RuntimeOrigin::signed(1), issuer, claim_type, None, None),
+				Error::<Test>::SelfAttestation
+			);
+			assert_noop!(
+				AuraCredentials::attest_claim(RuntimeOrigin::signed(1), [3u8; 32], claim_type, None, None),
+				Error::<Test>::SubjectNotFound
+			);
+			assert_noop!(
+				AuraCredentials::attest_claim(RuntimeOrigin::signed(1), subject, claim_type, Some(1), None),
+				Error::<Test>::InvalidClaimExpiry
+			);
+
+			assert_ok!(AuraCredentials::attest_claim(
+				RuntimeOrigin::signed(1),
+				subject,
+				claim_type,
+				Some(10),
+				Some([5u8; 32]),
+			));
+			let claim_id = AuraCredentials::claim_id(&issuer, &subject, &claim_type);
+			System::assert_last_event(Event::ClaimAttested { claim_id, issuer, subject, claim_type }.into());
+			assert_eq!(Balances::reserved_balance(1), 10);
+			assert_eq!(AuraCredentials::claims_issued_by(&issuer, None, 10), vec![(claim_id, subject)]);
+			assert_noop!(
+				AuraCredentials::attest_claim(RuntimeOrigin::signed(1), subject, claim_type, None, None),
+				Error::<Test>::ClaimAlreadyExists
+			);
+
+			// Срок действия включает блок `expiry`
+			let claim = AuraCredentials::claim(subject, claim_id).unwrap();
+			System::set_block_number(10);
+			assert!(AuraCredentials::is_claim_valid(&claim));
+			System::set_block_number(11);
+			assert!(!AuraCredentials::is_claim_valid(&claim));
+
+			// Отозвать может только издатель
+			assert_noop!(
+				AuraCredentials::revoke_claim(RuntimeOrigin::signed(2), issuer, claim_id),
+				Error::<Test>::NotClaimIssuer
+			);
+			assert_noop!(
+				AuraCredentials::revoke_claim(RuntimeOrigin::signed(2), subject, claim_id),
+				Error::<Test>::ClaimNotFound
+			);
+			assert_ok!(AuraCredentials::revoke_claim(RuntimeOrigin::signed(1), issuer, claim_id));
+			assert!(AuraCredentials::claim(subject, claim_id).is_none());
+			assert!(AuraCredentials::claims_issued_by(&issuer, None, 10).is_empty());
+			assert_eq!(Balances::reserved_balance(1), 0);
+		});
+	}
+
+	#[test]
+	fn test_hide_claim() {
+		new_test_ext().execute_with(|| {
+			let issuer = insert_aura_id(1);
+			let subject = insert_aura_id(2);
+			assert_ok!(AuraCredentials::attest_claim(RuntimeOrigin::signed(1), subject, [9u8; 32], None, None));
+			let claim_id = AuraCredentials::claim_id(&issuer, &subject, &[9u8; 32]);
+
+			// Скрыть может только субъект
+			assert_noop!(
+				AuraCredentials::set_claim_visibility(RuntimeOrigin::signed(1), claim_id, true),
+				Error::<Test>::ClaimNotFound
+			);
+			assert_ok!(AuraCredentials::set_claim_visibility(RuntimeOrigin::signed(2), claim_id, true));
+			assert!(AuraCredentials::claims_of(&subject, false).is_empty());
+			assert_eq!(AuraCredentials::claims_of(&subject, true).len(), 1);
+
+			assert_ok!(AuraCredentials::set_claim_visibility(RuntimeOrigin::signed(2), claim_id, false));
+			assert_eq!(AuraCredentials::claims_of(&subject, false).len(), 1);
+
+			// Утверждения деактивированного издателя недействительны
+			assert_ok!(AuraIdentity::force_deactivate_aura_id(RuntimeOrigin::root(), 1));
+			assert!(!AuraCredentials::is_claim_valid(&AuraCredentials::claim(subject, claim_id).unwrap()));
+		});
+	}
+
+	#[test]
+	fn test_claims_limit_and_revoke_after_deactivation() {
+		new_test_ext().execute_with(|| {
+			let issuer = insert_aura_id(1);
+			let subject = insert_aura_id(2);
+			for claim_type in [[1u8; 32], [2u8; 32]] {
+				assert_ok!(AuraCredentials::attest_claim(RuntimeOrigin::signed(1), subject, claim_type, None, None));
+			}
+			assert_noop!(
+				AuraCredentials::attest_claim(RuntimeOrigin::signed(1), subject, [3u8; 32], None, None),
+				Error::<Test>::TooManyClaims
+			);
+
+			// Постраничный обход утверждений издателя
+			let first = AuraCredentials::claims_issued_by(&issuer, None, 1);
+			assert_eq!(first.len(), 1);
+			let second = AuraCredentials::claims_issued_by(&issuer, Some(first[0].0), 1);
+			assert_eq!(second.len(), 1);
+			assert_ne!(first, second);
+			assert!(AuraCredentials::claims_issued_by(&issuer, Some(second[0].0), 1).is_empty());
+
+			// Деактивированный издатель отзывает утверждения и возвращает депозиты
+			assert_ok!(AuraIdentity::force_deactivate_aura_id(RuntimeOrigin::root(), 1));
+			for (claim_id, _) in first.into_iter().chain(second) {
+				assert_ok!(AuraCredentials::revoke_claim(RuntimeOrigin::signed(1), issuer, claim_id));
+			}
+			assert!(AuraCredentials::claims_of(&subject, true).is_empty());
+			assert_eq!(Balances::reserved_balance(1), 0);
+		});
+	}
+
+	#[test]
+	fn test_reject_claim_frees_slot() {
+		new_test_ext().execute_with(|| {
+			let spammer = insert_aura_id(1);
+			let subject = insert_aura_id(2);
+			let issuer = insert_aura_id(3);
+			for claim_type in [[1u8; 32], [2u8; 32]] {
+				assert_ok!(AuraCredentials::attest_claim(RuntimeOrigin::signed(1), subject, claim_type, None, None));
+			}
+			let spam_id = AuraCredentials::claim_id(&spammer, &subject, &[1u8; 32]);
+
+			// Скрытое утверждение по-прежнему занимает место
+			assert_ok!(AuraCredentials::set_claim_visibility(RuntimeOrigin::signed(2), spam_id, true));
+			assert_noop!(
+				AuraCredentials::attest_claim(RuntimeOrigin::signed(3), subject, [5u8; 32], None, None),
+				Error::<Test>::TooManyClaims
+			);
+
+			// Отклонить утверждение может только субъект
+			assert_noop!(
+				AuraCredentials::reject_claim(RuntimeOrigin::signed(3), spam_id),
+				Error::<Test>::ClaimNotFound
+			);
+			let reserved = Balances::reserved_balance(1);
+			assert_ok!(AuraCredentials::reject_claim(RuntimeOrigin::signed(2), spam_id));
+			System::assert_last_event(Event::ClaimRejected { claim_id: spam_id, subject }.into());
+			assert!(AuraCredentials::claim(subject, spam_id).is_none());
+			assert!(ClaimsByIssuer::<Test>::get(spammer, spam_id).is_none());
+			assert!(Balances::reserved_balance(1) < reserved);
+
+			assert_ok!(AuraCredentials::attest_claim(RuntimeOrigin::signed(3), subject, [5u8; 32], None, None));
+			let claim_id = AuraCredentials::claim_id(&issuer, &subject, &[5u8; 32]);
+			assert!(AuraCredentials::claim(subject, claim_id).is_some());
+		});
+	}
+
+	// Вспомогательная функция для тестов (используется и в бенчмарках)
+	pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: (1..=10).map(|account| (account, 1_000)).collect(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		let mut ext: sp_io::TestExternalities = t.into();
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,198 @@
+
+//! Weights for pallet_aura_credentials
+//!
+//! PLACEHOLDER WEIGHTS, NOT BENCHMARKED. The values are hand-written estimates
+//! based on the storage accesses of each call and have not been measured on
+//! reference hardware.
+//!
+//! TODO: regenerate with `./scripts/benchmark.sh pallet_aura_credentials` before the
+//! pallet is deployed to a production chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_aura_credentials.
+pub trait WeightInfo {
+	fn register_issuer() -> Weight;
+	fn unregister_issuer() -> Weight;
+	fn publish_schema() -> Weight;
+	fn remove_schema() -> Weight;
+	fn create_status_list(s: u32, ) -> Weight;
+	fn update_status(u: u32, ) -> Weight;
+	fn remove_status_list() -> Weight;
+	fn attest_claim() -> Weight;
+	fn revoke_claim() -> Weight;
+	fn set_claim_visibility() -> Weight;
+	fn reject_claim() -> Weight;
+}
+
+/// Weights for pallet_aura_credentials using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraCredentials Issuers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn register_issuer() -> Weight {
+		Weight::from_parts(27_318_000, 3_749)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuraCredentials Issuers (r:1 w:1)
+	/// Storage: AuraCredentials StatusLists (r:1 w:0)
+	/// Storage: AuraCredentials IssuerSchemas (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	fn unregister_issuer() -> Weight {
+		Weight::from_parts(32_704_000, 3_749)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraCredentials Issuers (r:1 w:0)
+	/// Storage: AuraCredentials Schemas (r:1 w:1)
+	/// Storage: AuraCredentials IssuerSchemas (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn publish_schema() -> Weight {
+		Weight::from_parts(33_052_000, 3_749)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraCredentials Issuers (r:1 w:0)
+	/// Storage: AuraCredentials Schemas (r:1 w:1)
+	/// Storage: AuraCredentials IssuerSchemas (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_schema() -> Weight {
+		Weight::from_parts(32_468_000, 3_749)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraCredentials Issuers (r:1 w:0)
+	/// Storage: AuraCredentials StatusLists (r:64 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraCredentials NextStatusListId (r:1 w:1)
+	/// The range of component `s` is `[1, 16384]`.
+	fn create_status_list(s: u32, ) -> Weight {
+		Weight::from_parts(158_926_000, 19_824)
+			.saturating_add(Weight::from_parts(1_047, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(68_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraCredentials Issuers (r:1 w:0)
+	/// Storage: AuraCredentials StatusLists (r:1 w:1)
+	/// The range of component `u` is `[0, 256]`.
+	fn update_status(u: u32, ) -> Weight {
+		Weight::from_parts(34_815_000, 19_950)
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraCredentials Issuers (r:1 w:0)
+	/// Storage: AuraCredentials StatusLists (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_status_list() -> Weight {
+		Weight::from_parts(36_291_000, 19_950)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity DidIndex (r:1 w:0)
+	/// Storage: AuraIdentity Tombstones (r:1 w:0)
+	/// Storage: AuraCredentials Claims (r:65 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraCredentials ClaimsByIssuer (r:0 w:1)
+	fn attest_claim() -> Weight {
+		Weight::from_parts(112_415_000, 20_471)
+			.saturating_add(T::DbWeight::get().reads(69_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraCredentials ClaimsByIssuer (r:1 w:1)
+	/// Storage: AuraCredentials Claims (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn revoke_claim() -> Weight {
+		Weight::from_parts(35_140_000, 3_831)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraCredentials Claims (r:1 w:1)
+	fn set_claim_visibility() -> Weight {
+		Weight::from_parts(19_583_000, 3_831)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraCredentials Claims (r:1 w:1)
+	/// Storage: AuraCredentials ClaimsByIssuer (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn reject_claim() -> Weight {
+		Weight::from_parts(34_712_000, 3_831)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn register_issuer() -> Weight {
+		Weight::from_parts(27_318_000, 3_749)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn unregister_issuer() -> Weight {
+		Weight::from_parts(32_704_000, 3_749)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn publish_schema() -> Weight {
+		Weight::from_parts(33_052_000, 3_749)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn remove_schema() -> Weight {
+		Weight::from_parts(32_468_000, 3_749)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn create_status_list(s: u32, ) -> Weight {
+		Weight::from_parts(158_926_000, 19_824)
+			.saturating_add(Weight::from_parts(1_047, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(68_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn update_status(u: u32, ) -> Weight {
+		Weight::from_parts(34_815_000, 19_950)
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_status_list() -> Weight {
+		Weight::from_parts(36_291_000, 19_950)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn attest_claim() -> Weight {
+		Weight::from_parts(112_415_000, 20_471)
+			.saturating_add(RocksDbWeight::get().reads(69_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn revoke_claim() -> Weight {
+		Weight::from_parts(35_140_000, 3_831)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn set_claim_visibility() -> Weight {
+		Weight::from_parts(19_583_000, 3_831)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn reject_claim() -> Weight {
+		Weight::from_parts(34_712_000, 3_831)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
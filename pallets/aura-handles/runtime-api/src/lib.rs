@@ -0,0 +1,31 @@
+//! Runtime API для Aura Handles
+//!
+//! Разрешение человекочитаемых имен в аккаунты и DID и обратный поиск имени
+//! аккаунта.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_aura_handles::HandleRecord;
+
+sp_api::decl_runtime_apis! {
+	pub trait AuraHandlesRuntimeApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Владелец действующего имени; имя нормализуется
+		fn resolve(name: Vec<u8>) -> Option<AccountId>;
+
+		/// DID владельца действующего имени
+		fn resolve_did(name: Vec<u8>) -> Option<[u8; 32]>;
+
+		/// Действующее нормализованное имя аккаунта; истекшее не возвращается
+		fn handle_of(account: AccountId) -> Option<Vec<u8>>;
+
+		/// Регистрация имени, в том числе истекшая
+		fn handle(name: Vec<u8>) -> Option<HandleRecord<AccountId, Balance, BlockNumber>>;
+	}
+}
@@ -0,0 +1,125 @@
+//! Бенчмарки паллета aura-handles
+
+use super::*;
+use crate::Pallet as AuraHandles;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	pallet_prelude::Encode,
+	traits::{Currency, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_aura_identity::{AuraIdRecord, AuraIdentities, AuraPublicKey, BalanceOf, DidIndex};
+use sp_runtime::traits::{Bounded, Saturating, StaticLookup};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(event.into());
+}
+
+/// Аккаунт с балансом и Aura ID. Запись вставляется напрямую: доказательство
+/// владения ключом измеряется в бенчмарках aura-identity.
+fn identity<T: Config>(index: u32) -> T::AccountId {
+	let who: T::AccountId = account("aura_id", index, SEED);
+	<T as pallet_aura_identity::Config>::Currency::make_free_balance_be(
+		&who,
+		BalanceOf::<T>::max_value() / 4u32.into(),
+	);
+
+	let did = sp_io::hashing::blake2_256(&who.encode());
+	AuraIdentities::<T>::insert(&who, AuraIdRecord {
+		did,
+		public_key: AuraPublicKey::Sr25519(did),
+		recovery_config: BoundedVec::default(),
+		created: Some(frame_system::Pallet::<T>::block_number()),
+	});
+	DidIndex::<T>::insert(did, who.clone());
+	who
+}
+
+/// Имя максимальной длины
+fn name<T: Config>() -> Vec<u8> {
+	vec![b'a'; T::MaxHandleLength::get() as usize]
+}
+
+fn registered<T: Config>(index: u32) -> T::AccountId {
+	let who = identity::<T>(index);
+	AuraHandles::<T>::register_handle(RawOrigin::Signed(who.clone()).into(), name::<T>())
+		.expect("name is free and valid");
+	who
+}
+
+/// Переводит цепочку за срок действия имени
+fn expire<T: Config>() {
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(
+		now.saturating_add(T::RegistrationPeriod::get()).saturating_add(1u32.into()),
+	);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// Худший случай: имя занято истекшей регистрацией другого аккаунта
+	#[benchmark]
+	fn register_handle() {
+		registered::<T>(0);
+		expire::<T>();
+		let who = identity::<T>(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), name::<T>());
+
+		assert_eq!(AuraHandles::<T>::resolve(&name::<T>()), Some(who));
+	}
+
+	#[benchmark]
+	fn renew_handle() {
+		let who = registered::<T>(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who));
+
+		let handle = AuraHandles::<T>::normalize(&name::<T>()).expect("name is valid");
+		let expires = Handles::<T>::get(&handle).expect("name is registered").expires;
+		assert_last_event::<T>(Event::HandleRenewed { handle, expires }.into());
+	}
+
+	#[benchmark]
+	fn transfer_handle() {
+		let who = registered::<T>(0);
+		let to = identity::<T>(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), T::Lookup::unlookup(to.clone()));
+
+		assert_eq!(AuraHandles::<T>::resolve(&name::<T>()), Some(to));
+	}
+
+	#[benchmark]
+	fn release_handle() {
+		let who = registered::<T>(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()));
+
+		assert!(!AccountHandles::<T>::contains_key(&who));
+	}
+
+	#[benchmark]
+	fn remove_expired_handle() {
+		let owner = registered::<T>(0);
+		expire::<T>();
+		let caller = identity::<T>(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name::<T>());
+
+		assert!(!AccountHandles::<T>::contains_key(&owner));
+	}
+
+	impl_benchmark_test_suite!(AuraHandles, crate::tests::new_test_ext(), crate::tests::Test);
+}
@@ -0,0 +1,604 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Реестр человекочитаемых имен (handles) для Aura ID.
+//!
+//! Аккаунт с Aura ID регистрирует одно уникальное имя под депозит на
+//! `RegistrationPeriod` блоков. Имена нормализуются: латиница приводится к
+//! нижнему регистру, допустимы `a-z`, `0-9`, `-` и `_` (не в начале и не в
+//! конце). Истекшее имя перестает разрешаться и может быть занято заново.
+//!
+//! Паллет реализует `StaticLookup` для аккаунтов (`MultiAddress::Raw(имя)`)
+//! и DID (`DidLookup`), поэтому вызовы Aura Identity принимают имя вместо
+//! доверенного контакта или DID. Остальные аккаунты (переводы, владелец при
+//! восстановлении) имена не принимают, чтобы опечатка или истекшее имя не
+//! перенаправили средства.
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{BalanceStatus, ReservableCurrency},
+		Blake2_128Concat, BoundedVec,
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_aura_identity::{AccountIdLookupOf, AuraIdentities, BalanceOf};
+	use sp_runtime::{
+		traits::{LookupError, Saturating, StaticLookup},
+		MultiAddress,
+	};
+	use sp_std::{marker::PhantomData, vec::Vec};
+	use scale_info::TypeInfo;
+	use crate::weights::WeightInfo;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_aura_identity::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Депозит за имя, возвращается при освобождении
+		#[pallet::constant]
+		type HandleDeposit: Get<BalanceOf<Self>>;
+
+		/// Минимальная длина имени
+		#[pallet::constant]
+		type MinHandleLength: Get<u32>;
+
+		/// Максимальная длина имени
+		#[pallet::constant]
+		type MaxHandleLength: Get<u32>;
+
+		/// На сколько блоков регистрируется и продлевается имя
+		#[pallet::constant]
+		type RegistrationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Веса экстринсиков паллета
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Нормализованное имя
+	pub type HandleOf<T> = BoundedVec<u8, <T as Config>::MaxHandleLength>;
+
+	// ========== СТРУКТУРЫ ==========
+
+	/// Регистрация имени
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct HandleRecord<AccountId, Balance, BlockNumber> {
+		/// Владелец имени
+		pub owner: AccountId,
+		/// Депозит владельца
+		pub deposit: Balance,
+		/// Последний блок, в котором имя действует
+		pub expires: BlockNumber,
+	}
+
+	// ========== STORAGE ==========
+
+	#[pallet::storage]
+	#[pallet::getter(fn handle)]
+	/// Регистрации по нормализованному имени
+	pub type Handles<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		HandleOf<T>,
+		HandleRecord<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	/// Обратный поиск: имя аккаунта (может быть истекшим, см. `handle_of`)
+	pub type AccountHandles<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, HandleOf<T>, OptionQuery>;
+
+	// ========== СОБЫТИЯ ==========
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		HandleRegistered {
+			handle: HandleOf<T>,
+			owner: T::AccountId,
+			expires: BlockNumberFor<T>
+		},
+		HandleRenewed { handle: HandleOf<T>, expires: BlockNumberFor<T> },
+		HandleTransferred { handle: HandleOf<T>, from: T::AccountId, to: T::AccountId },
+		HandleReleased { handle: HandleOf<T>, owner: T::AccountId },
+	}
+
+	// ========== ОШИБКИ ==========
+
+	#[pallet::error]
+	pub enum Error<T> {
+		AuraIdNotFound,
+		InvalidHandle,
+		HandleTaken,
+		HandleNotFound,
+		HandleExpired,
+		HandleNotExpired,
+		AlreadyHasHandle,
+	}
+
+	// ========== CALL ФУНКЦИИ ==========
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		// Регистрация имени. Истекшее имя другого владельца освобождается
+		// с возвратом его депозита.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_handle())]
+		pub fn register_handle(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(AuraIdentities::<T>::contains_key(&who), Error::<T>::AuraIdNotFound);
+			ensure!(!AccountHandles::<T>::contains_key(&who), Error::<T>::AlreadyHasHandle);
+			let handle = Self::normalize(&name)?;
+
+			if let Some(record) = Handles::<T>::get(&handle) {
+				ensure!(Self::is_expired(&record), Error::<T>::HandleTaken);
+				Self::release(&handle, record);
+			}
+
+			let deposit = <T as Config>::HandleDeposit::get();
+			<T as pallet_aura_identity::Config>::Currency::reserve(&who, deposit)?;
+
+			let expires = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::RegistrationPeriod::get());
+			Handles::<T>::insert(&handle, HandleRecord { owner: who.clone(), deposit, expires });
+			AccountHandles::<T>::insert(&who, &handle);
+
+			Self::deposit_event(Event::HandleRegistered { handle, owner: who, expires });
+
+			Ok(())
+		}
+
+		// Продление имени на `RegistrationPeriod` от текущего срока (или от
+		// текущего блока, если имя уже истекло, но еще не занято)
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew_handle())]
+		pub fn renew_handle(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let handle = AccountHandles::<T>::get(&who).ok_or(Error::<T>::HandleNotFound)?;
+			let expires = Handles::<T>::try_mutate(&handle, |maybe_record| {
+				let record = maybe_record.as_mut().ok_or(Error::<T>::HandleNotFound)?;
+				let now = frame_system::Pallet::<T>::block_number();
+				record.expires = record.expires.max(now).saturating_add(T::RegistrationPeriod::get());
+				Ok::<_, DispatchError>(record.expires)
+			})?;
+
+			Self::deposit_event(Event::HandleRenewed { handle, expires });
+
+			Ok(())
+		}
+
+		// Передача имени другому аккаунту с Aura ID. Депозит переходит к
+		// получателю и вернется ему при освобождении имени.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_handle())]
+		pub fn transfer_handle(origin: OriginFor<T>, to: AccountIdLookupOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+
+			let handle = AccountHandles::<T>::get(&who).ok_or(Error::<T>::HandleNotFound)?;
+			let mut record = Handles::<T>::get(&handle).ok_or(Error::<T>::HandleNotFound)?;
+			ensure!(!Self::is_expired(&record), Error::<T>::HandleExpired);
+			ensure!(AuraIdentities::<T>::contains_key(&to), Error::<T>::AuraIdNotFound);
+			ensure!(!AccountHandles::<T>::contains_key(&to), Error::<T>::AlreadyHasHandle);
+
+			let remaining = <T as pallet_aura_identity::Config>::Currency::repatriate_reserved(
+				&who,
+				&to,
+				record.deposit,
+				BalanceStatus::Reserved,
+			)?;
+			record.deposit = record.deposit.saturating_sub(remaining);
+			record.owner = to.clone();
+
+			Handles::<T>::insert(&handle, record);
+			AccountHandles::<T>::remove(&who);
+			AccountHandles::<T>::insert(&to, &handle);
+
+			Self::deposit_event(Event::HandleTransferred { handle, from: who, to });
+
+			Ok(())
+		}
+
+		// Освобождение своего имени с возвратом депозита
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_handle())]
+		pub fn release_handle(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let handle = AccountHandles::<T>::get(&who).ok_or(Error::<T>::HandleNotFound)?;
+			let record = Handles::<T>::get(&handle).ok_or(Error::<T>::HandleNotFound)?;
+			Self::release(&handle, record);
+
+			Ok(())
+		}
+
+		// Удаление истекшего имени любым аккаунтом. Депозит возвращается владельцу.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_expired_handle())]
+		pub fn remove_expired_handle(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let handle = Self::normalize(&name)?;
+			let record = Handles::<T>::get(&handle).ok_or(Error::<T>::HandleNotFound)?;
+			ensure!(Self::is_expired(&record), Error::<T>::HandleNotExpired);
+			Self::release(&handle, record);
+
+			Ok(())
+		}
+	}
+
+	// ========== ВСПОМОГАТЕЛЬНЫЕ ФУНКЦИИ ==========
+
+	impl<T: Config> Pallet<T> {
+		/// Нормализует имя: ASCII в нижнем регистре, символы `a-z0-9-_`,
+		/// без `-` и `_` по краям, длина в пределах `MinHandleLength..=MaxHandleLength`
+		pub fn normalize(name: &[u8]) -> Result<HandleOf<T>, Error<T>> {
+			let handle = name.to_ascii_lowercase();
+			ensure!(handle.len() as u32 >= T::MinHandleLength::get(), Error::<T>::InvalidHandle);
+			ensure!(
+				handle.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-' || *c == b'_'),
+				Error::<T>::InvalidHandle
+			);
+			let is_separator = |c: Option<&u8>| matches!(c, Some(b'-') | Some(b'_'));
+			ensure!(
+				!is_separator(handle.first()) && !is_separator(handle.last()),
+				Error::<T>::InvalidHandle
+			);
+			handle.try_into().map_err(|_| Error::<T>::InvalidHandle)
+		}
+
+		fn is_expired(record: &HandleRecord<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>) -> bool {
+			frame_system::Pallet::<T>::block_number() > record.expires
+		}
+
+		fn release(handle: &HandleOf<T>, record: HandleRecord<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>) {
+			Handles::<T>::remove(handle);
+			AccountHandles::<T>::remove(&record.owner);
+			<T as pallet_aura_identity::Config>::Currency::unreserve(&record.owner, record.deposit);
+
+			Self::deposit_event(Event::HandleReleased { handle: handle.clone(), owner: record.owner });
+		}
+
+		/// Действующее имя аккаунта
+		pub fn handle_of(account: &T::AccountId) -> Option<HandleOf<T>> {
+			let handle = AccountHandles::<T>::get(account)?;
+			Handles::<T>::get(&handle)
+				.filter(|record| !Self::is_expired(record))
+				.map(|_| handle)
+		}
+
+		/// Владелец действующего имени
+		pub fn resolve(name: &[u8]) -> Option<T::AccountId> {
+			let handle = Self::normalize(name).ok()?;
+			Handles::<T>::get(handle)
+				.filter(|record| !Self::is_expired(record))
+				.map(|record| record.owner)
+		}
+
+		/// DID владельца действующего имени
+		pub fn resolve_did(name: &[u8]) -> Option<[u8; 32]> {
+			AuraIdentities::<T>::get(Self::resolve(name)?).map(|record| record.did)
+		}
+	}
+
+	/// Аккаунт по адресу: `Id(аккаунт)` или `Raw(имя)`
+	impl<T: Config> StaticLookup for Pallet<T> {
+		type Source = MultiAddress<T::AccountId, ()>;
+		type Target = T::AccountId;
+
+		fn lookup(address: Self::Source) -> Result<Self::Target, LookupError> {
+			match address {
+				MultiAddress::Id(account) => Ok(account),
+				MultiAddress::Raw(name) => Self::resolve(&name).ok_or(LookupError),
+				_ => Err(LookupError),
+			}
+		}
+
+		fn unlookup(account: Self::Target) -> Self::Source {
+			MultiAddress::Id(account)
+		}
+	}
+
+	/// DID по адресу: `Address32(DID)`, `Id(аккаунт)` - DID его Aura ID,
+	/// `Raw(имя)` - DID владельца имени
+	pub struct DidLookup<T>(PhantomData<T>);
+
+	impl<T: Config> StaticLookup for DidLookup<T> {
+		type Source = MultiAddress<T::AccountId, ()>;
+		type Target = [u8; 32];
+
+		fn lookup(address: Self::Source) -> Result<Self::Target, LookupError> {
+			match address {
+				MultiAddress::Address32(did) => Ok(did),
+				MultiAddress::Id(account) => AuraIdentities::<T>::get(account)
+					.map(|record| record.did)
+					.ok_or(LookupError),
+				MultiAddress::Raw(name) => Pallet::<T>::resolve_did(&name).ok_or(LookupError),
+				_ => Err(LookupError),
+			}
+		}
+
+		fn unlookup(did: Self::Target) -> Self::Source {
+			MultiAddress::Address32(did)
+		}
+	}
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+	use frame_support::{
+		assert_noop, assert_ok,
+		traits::{ConstU128, ConstU32, ConstU64, ReservableCurrency},
+		BoundedVec,
+	};
+	use sp_core::H256;
+	use sp_runtime::{
+		traits::{AccountIdLookup, BlakeTwo256, StaticLookup},
+		BuildStorage, MultiAddress,
+	};
+
+	type Block = frame_system::mocking::MockBlock<Test>;
+
+	frame_support::construct_runtime!(
+		pub enum Test {
+			System: frame_system,
+			Balances: pallet_balances,
+			AuraIdentity: pallet_aura_identity,
+			AuraHandles: pallet,
+		}
+	);
+
+	frame_support::parameter_types! {
+		pub TestSlashBeneficiary: Option<u64> = None;
+		pub const TestRecoveryBondSlash: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	}
+
+	impl frame_system::Config for Test {
+		type BaseCallFilter = frame_support::traits::Everything;
+		type BlockWeights = ();
+		type BlockLength = ();
+		type DbWeight = ();
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeCall = RuntimeCall;
+		type Nonce = u64;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = AccountIdLookup<u64, ()>;
+		type Block = Block;
+		type RuntimeEvent = RuntimeEvent;
+		type BlockHashCount = frame_support::traits::ConstU64<250>;
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<u128>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ();
+		type OnSetCode = ();
+		type MaxConsumers = frame_support::traits::ConstU32<16>;
+	}
+
+	impl pallet_balances::Config for Test {
+		type Balance = u128;
+		type RuntimeEvent = RuntimeEvent;
+		type DustRemoval = ();
+		type ExistentialDeposit = ConstU128<1>;
+		type AccountStore = System;
+		type WeightInfo = ();
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type HoldIdentifier = ();
+		type FreezeIdentifier = ();
+		type MaxHolds = ();
+		type MaxFreezes = ();
+	}
+
+	impl pallet_aura_identity::Config for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type Currency = Balances;
+		type MaxTrustees = ConstU32<10>;
+		type MaxTrusteeships = ConstU32<16>;
+		type RecoveryDeposit = ConstU128<100>;
+		type RecoveryBond = ConstU128<50>;
+		type RecoveryBondSlash = TestRecoveryBondSlash;
+		type SlashBeneficiary = TestSlashBeneficiary;
+		type DefaultRecoveryDelay = ConstU32<100>;
+		type MinRecoveryDelay = ConstU32<10>;
+		type MaxRecoveryDelay = ConstU32<1_000>;
+		type RecoveryLifetime = ConstU32<50>;
+		type ShareComplaintPeriod = ConstU32<20>;
+		type MaxKeyHistory = ConstU32<2>;
+		type MaxVerificationMethods = ConstU32<2>;
+		type MaxServices = ConstU32<2>;
+		type MaxControllers = ConstU32<2>;
+		type DocumentDepositBase = ConstU128<10>;
+		type DocumentByteDeposit = ConstU128<1>;
+		type ForceOrigin = frame_system::EnsureRoot<u64>;
+		type DidLookup = DidLookup<Test>;
+		// Доверенных контактов можно указывать именами
+		type TrusteeLookup = AuraHandles;
+		type WeightInfo = ();
+	}
+
+	impl Config for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type HandleDeposit = ConstU128<20>;
+		type MinHandleLength = ConstU32<3>;
+		type MaxHandleLength = ConstU32<16>;
+		type RegistrationPeriod = ConstU64<100>;
+		type WeightInfo = ();
+	}
+
+	// Aura ID с DID `[account; 32]` без доказательства владения ключом
+	pub(crate) fn insert_aura_id(account: u64) -> [u8; 32] {
+		let did = [account as u8; 32];
+		pallet_aura_identity::AuraIdentities::<Test>::insert(account, pallet_aura_identity::AuraIdRecord {
+			did,
+			public_key: pallet_aura_identity::AuraPublicKey::Sr25519([account as u8; 32]),
+			recovery_config: BoundedVec::default(),
+			created: Some(1),
+		});
+		pallet_aura_identity::DidIndex::<Test>::insert(did, account);
+		did
+	}
+
+	fn raw(name: &str) -> MultiAddress<u64, ()> {
+		MultiAddress::Raw(name.as_bytes().to_vec())
+	}
+
+	#[test]
+	fn test_register_and_resolve_handle() {
+		new_test_ext().execute_with(|| {
+			let did = insert_aura_id(1);
+			insert_aura_id(2);
+
+			assert_noop!(
+				AuraHandles::register_handle(RuntimeOrigin::signed(3), b"carol".to_vec()),
+				Error::<Test>::AuraIdNotFound
+			);
+			for invalid in [&b"al"[..], b"-alice", b"alice_", b"al ice", b"alice.aura", b"averyveryverylongname"] {
+				assert_noop!(
+					AuraHandles::register_handle(RuntimeOrigin::signed(1), invalid.to_vec()),
+					Error::<Test>::InvalidHandle
+				);
+			}
+
+			// Имя нормализуется к нижнему регистру
+			assert_ok!(AuraHandles::register_handle(RuntimeOrigin::signed(1), b"Alice-01".to_vec()));
+			let handle = AuraHandles::handle_of(&1).unwrap();
+			assert_eq!(handle.to_vec(), b"alice-01".to_vec());
+			assert_eq!(AuraHandles::handle(&handle).unwrap().expires, 101);
+			assert_eq!(Balances::reserved_balance(1), 20);
+
+			assert_eq!(AuraHandles::resolve(b"ALICE-01"), Some(1));
+			assert_eq!(AuraHandles::resolve_did(b"alice-01"), Some(did));
+			assert_eq!(DidLookup::<Test>::lookup(raw("alice-01")), Ok(did));
+
+			assert_noop!(
+				AuraHandles::register_handle(RuntimeOrigin::signed(2), b"alice-01".to_vec()),
+				Error::<Test>::HandleTaken
+			);
+			assert_noop!(
+				AuraHandles::register_handle(RuntimeOrigin::signed(1), b"alice".to_vec()),
+				Error::<Test>::AlreadyHasHandle
+			);
+
+			assert_ok!(AuraHandles::release_handle(RuntimeOrigin::signed(1)));
+			assert!(AuraHandles::resolve(b"alice-01").is_none());
+			assert!(AuraHandles::handle_of(&1).is_none());
+			assert_eq!(Balances::reserved_balance(1), 0);
+		});
+	}
+
+	#[test]
+	fn test_handle_expiry_and_transfer() {
+		new_test_ext().execute_with(|| {
+			insert_aura_id(1);
+			insert_aura_id(2);
+			insert_aura_id(3);
+			assert_ok!(AuraHandles::register_handle(RuntimeOrigin::signed(1), b"alice".to_vec()));
+
+			// Депозит переходит к новому владельцу
+			assert_ok!(AuraHandles::transfer_handle(RuntimeOrigin::signed(1), MultiAddress::Id(2)));
+			assert_eq!(AuraHandles::resolve(b"alice"), Some(2));
+			assert!(AuraHandles::handle_of(&1).is_none());
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Balances::reserved_balance(2), 20);
+
+			// Продление до истечения прибавляет период к текущему сроку
+			System::set_block_number(50);
+			assert_ok!(AuraHandles::renew_handle(RuntimeOrigin::signed(2)));
+			assert_eq!(AuraHandles::handle(AuraHandles::handle_of(&2).unwrap()).unwrap().expires, 201);
+
+			System::set_block_number(201);
+			assert_noop!(
+				AuraHandles::remove_expired_handle(RuntimeOrigin::signed(3), b"alice".to_vec()),
+				Error::<Test>::HandleNotExpired
+			);
+
+			// Истекшее имя не разрешается, его можно занять заново
+			System::set_block_number(202);
+			assert!(AuraHandles::resolve(b"alice").is_none());
+			assert!(AuraHandles::handle_of(&2).is_none());
+			assert_noop!(
+				AuraHandles::transfer_handle(RuntimeOrigin::signed(2), MultiAddress::Id(3)),
+				Error::<Test>::HandleExpired
+			);
+			assert_ok!(AuraHandles::register_handle(RuntimeOrigin::signed(3), b"alice".to_vec()));
+			assert_eq!(AuraHandles::resolve(b"alice"), Some(3));
+			assert!(AuraHandles::handle_of(&2).is_none());
+			assert_eq!(Balances::reserved_balance(2), 0);
+		});
+	}
+
+	#[test]
+	fn test_identity_calls_accept_handles() {
+		new_test_ext().execute_with(|| {
+			insert_aura_id(1);
+			let bob_did = insert_aura_id(2);
+			insert_aura_id(3);
+			assert_ok!(AuraHandles::register_handle(RuntimeOrigin::signed(2), b"bob".to_vec()));
+			assert_ok!(AuraHandles::register_handle(RuntimeOrigin::signed(3), b"carol".to_vec()));
+
+			// Доверенные контакты и контроллеры указываются именами
+			assert_ok!(AuraIdentity::setup_recovery(
+				RuntimeOrigin::signed(1),
+				2,
+				vec![raw("bob"), raw("Carol")],
+				None,
+			));
+			assert!(pallet_aura_identity::TrusteeShares::<Test>::contains_key(1, 3));
+			assert_ok!(AuraIdentity::accept_trusteeship(RuntimeOrigin::signed(2), MultiAddress::Id(1)));
+
+			assert_ok!(AuraIdentity::add_controller(RuntimeOrigin::signed(1), raw("bob")));
+			assert!(pallet_aura_identity::Controllers::<Test>::contains_key(1, bob_did));
+			assert_ok!(AuraIdentity::remove_controller(RuntimeOrigin::signed(1), MultiAddress::Address32(bob_did)));
+
+			assert_noop!(
+				AuraIdentity::add_trustee(RuntimeOrigin::signed(1), raw("dave")),
+				sp_runtime::DispatchError::CannotLookup
+			);
+
+			// Остальные аккаунты, в том числе в переводах, именами не указываются
+			assert_noop!(
+				Balances::transfer_keep_alive(RuntimeOrigin::signed(1), raw("bob"), 10),
+				sp_runtime::DispatchError::CannotLookup
+			);
+			assert_noop!(
+				AuraIdentity::initiate_recovery(
+					RuntimeOrigin::signed(4),
+					raw("bob"),
+					pallet_aura_identity::AuraPublicKey::Sr25519([7u8; 32]),
+				),
+				sp_runtime::DispatchError::CannotLookup
+			);
+		});
+	}
+
+	// Вспомогательная функция для тестов (используется и в бенчмарках)
+	pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: (1..=10).map(|account| (account, 1_000)).collect(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		let mut ext: sp_io::TestExternalities = t.into();
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
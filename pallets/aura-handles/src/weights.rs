@@ -0,0 +1,100 @@
+
+//! Weights for pallet_aura_handles
+//!
+//! PLACEHOLDER WEIGHTS, NOT BENCHMARKED. The values are hand-written estimates
+//! based on the storage accesses of each call and have not been measured on
+//! reference hardware.
+//!
+//! TODO: regenerate with `./scripts/benchmark.sh pallet_aura_handles` before the
+//! pallet is deployed to a production chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_aura_handles.
+pub trait WeightInfo {
+	fn register_handle() -> Weight;
+	fn renew_handle() -> Weight;
+	fn transfer_handle() -> Weight;
+	fn release_handle() -> Weight;
+	fn remove_expired_handle() -> Weight;
+}
+
+/// Weights for pallet_aura_handles using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraHandles AccountHandles (r:2 w:2)
+	/// Storage: AuraHandles Handles (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn register_handle() -> Weight {
+		Weight::from_parts(41_927_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: AuraHandles AccountHandles (r:1 w:0)
+	/// Storage: AuraHandles Handles (r:1 w:1)
+	fn renew_handle() -> Weight {
+		Weight::from_parts(18_306_000, 3_541)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraHandles AccountHandles (r:2 w:2)
+	/// Storage: AuraHandles Handles (r:1 w:1)
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: System Account (r:2 w:2)
+	fn transfer_handle() -> Weight {
+		Weight::from_parts(45_218_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: AuraHandles AccountHandles (r:1 w:1)
+	/// Storage: AuraHandles Handles (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn release_handle() -> Weight {
+		Weight::from_parts(30_574_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraHandles Handles (r:1 w:1)
+	/// Storage: AuraHandles AccountHandles (r:0 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_expired_handle() -> Weight {
+		Weight::from_parts(29_813_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn register_handle() -> Weight {
+		Weight::from_parts(41_927_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn renew_handle() -> Weight {
+		Weight::from_parts(18_306_000, 3_541)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn transfer_handle() -> Weight {
+		Weight::from_parts(45_218_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn release_handle() -> Weight {
+		Weight::from_parts(30_574_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn remove_expired_handle() -> Weight {
+		Weight::from_parts(29_813_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
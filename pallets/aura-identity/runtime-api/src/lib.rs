@@ -0,0 +1,76 @@
+//! Runtime API для Aura Identity
+//!
+//! Типизированные запросы к состоянию паллета: Aura ID по аккаунту, аккаунт по
+//! DID, содержимое DID документа, конфигурация и политика восстановления,
+//! резервный ключ, доверенные контакты, их шары секрета и активное восстановление.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_aura_identity::{
+	AuraIdRecord, AuraPublicKey, BackupKey, DidDocument, DocumentVersion, PolicyNode,
+	RecoveryConfig, RecoveryPolicy, RecoveryStatus, ServiceEndpoint, ShareCommitmentSet,
+	ShareInfo, ShareStatus, Tombstone, TrusteeInfo, VerificationMethod,
+};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(8)]
+	pub trait AuraIdentityRuntimeApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Aura ID аккаунта
+		fn identity(account: AccountId) -> Option<AuraIdRecord<BlockNumber>>;
+
+		/// Аккаунт, которому принадлежит DID; `None` для деактивированного DID
+		fn account_by_did(did: [u8; 32]) -> Option<AccountId>;
+
+		/// Конфигурация восстановления аккаунта
+		fn recovery_config(account: AccountId) -> Option<RecoveryConfig<Balance>>;
+
+		/// Доверенные контакты аккаунта и их статус
+		fn trustees(account: AccountId) -> Vec<TrusteeInfo<AccountId>>;
+
+		/// Активное восстановление аккаунта
+		fn active_recovery(account: AccountId) -> Option<RecoveryStatus<AccountId, Balance, BlockNumber>>;
+
+		/// Ключ Aura ID, действовавший в блоке `at`
+		fn key_at(account: AccountId, at: BlockNumber) -> Option<AuraPublicKey>;
+
+		/// Ключ Aura ID без алгоритма (до версии 3)
+		#[changed_in(3)]
+		fn key_at(account: AccountId, at: BlockNumber) -> Option<[u8; 32]>;
+
+		/// Запись о деактивации DID, если он деактивирован
+		#[api_version(2)]
+		fn tombstone(did: [u8; 32]) -> Option<Tombstone<AccountId, BlockNumber>>;
+
+		/// Политика восстановления аккаунта, если она задана
+		#[api_version(4)]
+		fn recovery_policy(account: AccountId) -> Option<RecoveryPolicy<AccountId>>;
+
+		/// Резервный ключ восстановления аккаунта
+		#[api_version(5)]
+		fn backup_key(account: AccountId) -> Option<BackupKey<Balance>>;
+
+		/// Обязательства на шары секрета аккаунта
+		#[api_version(6)]
+		fn share_commitments(account: AccountId) -> Option<ShareCommitmentSet<BlockNumber>>;
+
+		/// Индексы шаров доверенных контактов и результат их проверки
+		#[api_version(6)]
+		fn share_states(account: AccountId) -> Vec<(AccountId, ShareInfo<BlockNumber>)>;
+
+		/// Содержимое DID документа; `None` для неизвестного или деактивированного DID
+		#[api_version(7)]
+		fn did_document(did: [u8; 32]) -> Option<DidDocument<Balance, BlockNumber>>;
+
+		/// Контакты, на жалобы которых владелец не ответил вовремя: их шары
+		/// некорректны, и секрет нужно разделить заново
+		#[api_version(8)]
+		fn invalid_shares(account: AccountId) -> Vec<AccountId>;
+	}
+}
@@ -0,0 +1,731 @@
+//! Бенчмарки паллета aura-identity
+
+use super::*;
+use crate::Pallet as AuraIdentity;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	pallet_prelude::Encode,
+	traits::{Currency, Get},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{Bounded, StaticLookup};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+fn assert_last_event<T: Config>(event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(event.into());
+}
+
+/// Аккаунт с балансом, достаточным для всех депозитов
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 4u32.into());
+	who
+}
+
+/// P-256 ключ, полученный из `seed`, и его подпись над `message`.
+/// P-256 проверяется в runtime без host-функции - самый дорогой алгоритм.
+fn p256_sign(seed: impl Encode, message: &[u8]) -> (AuraPublicKey, AuraSignature) {
+	use p256::ecdsa::{signature::Signer, Signature, SigningKey};
+
+	let secret = (b"aura-identity:bench", seed).using_encoded(sp_io::hashing::blake2_256);
+	let signing_key = SigningKey::from_slice(&secret).expect("hash is a valid P-256 scalar; qed");
+	let public = signing_key.verifying_key().to_encoded_point(true);
+	let signature: Signature = signing_key.sign(message);
+	(
+		AuraPublicKey::P256(public.as_bytes().try_into().expect("compressed point is 33 bytes; qed")),
+		AuraSignature::P256(signature.to_bytes().as_slice().try_into().expect("signature is 64 bytes; qed")),
+	)
+}
+
+/// Новый P-256 ключ и подпись им над текущим `key_proof_payload` аккаунта
+fn key_proof<T: Config>(who: &T::AccountId) -> (AuraPublicKey, AuraSignature) {
+	let nonce = KeyProofNonces::<T>::get(who);
+	// Для каждого nonce свой ключ
+	p256_sign((who, nonce), &AuraIdentity::<T>::key_proof_payload(who, nonce))
+}
+
+fn create_identity<T: Config>(who: &T::AccountId) {
+	let (public_key, proof) = key_proof::<T>(who);
+	AuraIdentity::<T>::create_aura_id(RawOrigin::Signed(who.clone()).into(), public_key, Vec::new(), proof)
+		.expect("fresh account can create an Aura ID");
+}
+
+/// Владелец с Aura ID и настроенным восстановлением через `t` доверенных контактов
+fn setup_trustees<T: Config>(t: u32, confirmed: bool) -> (T::AccountId, Vec<T::AccountId>) {
+	setup_trustees_with_threshold::<T>(t, MIN_THRESHOLD, confirmed)
+}
+
+/// То же, что `setup_trustees`, с заданным порогом
+fn setup_trustees_with_threshold<T: Config>(
+	t: u32,
+	threshold: u8,
+	confirmed: bool,
+) -> (T::AccountId, Vec<T::AccountId>) {
+	let owner = funded_account::<T>("owner", 0);
+	create_identity::<T>(&owner);
+
+	let trustees: Vec<T::AccountId> = (0..t)
+		.map(|i| {
+			let trustee = funded_account::<T>("trustee", i);
+			create_identity::<T>(&trustee);
+			trustee
+		})
+		.collect();
+
+	AuraIdentity::<T>::setup_recovery(
+		RawOrigin::Signed(owner.clone()).into(),
+		threshold,
+		trustees.iter().cloned().map(T::TrusteeLookup::unlookup).collect(),
+		None,
+	)
+	.expect("valid recovery setup");
+
+	if confirmed {
+		for trustee in &trustees {
+			AuraIdentity::<T>::accept_trusteeship(
+				RawOrigin::Signed(trustee.clone()).into(),
+				T::Lookup::unlookup(owner.clone()),
+			)
+			.expect("trustee was just added");
+		}
+	}
+
+	(owner, trustees)
+}
+
+/// Активное восстановление, которое поддержали все `t` доверенных контактов
+fn setup_vouched_recovery<T: Config>(t: u32) -> (T::AccountId, T::AccountId) {
+	let (owner, trustees) = setup_trustees::<T>(t, true);
+	let rescuer = funded_account::<T>("rescuer", 0);
+	AuraIdentity::<T>::initiate_recovery(
+		RawOrigin::Signed(rescuer.clone()).into(),
+		T::Lookup::unlookup(owner.clone()),
+		AuraPublicKey::Sr25519([7u8; 32]),
+	)
+	.expect("recovery is configured");
+	for trustee in trustees {
+		AuraIdentity::<T>::submit_recovery_share(
+			RawOrigin::Signed(trustee).into(),
+			T::Lookup::unlookup(owner.clone()),
+		)
+		.expect("trustee is confirmed");
+	}
+	(owner, rescuer)
+}
+
+/// Аккаунт `trustee` - доверенный контакт у `MaxTrusteeships` владельцев.
+/// У каждого владельца политика ссылается на него и идет восстановление,
+/// которое он уже подтвердил.
+fn setup_trusteeships<T: Config>(trustee: &T::AccountId) {
+	for i in 0..T::MaxTrusteeships::get() {
+		let owner = funded_account::<T>("trusted_by", i);
+		create_identity::<T>(&owner);
+		let other = funded_account::<T>("co_trustee", i);
+		create_identity::<T>(&other);
+		let trustees = [trustee.clone(), other];
+
+		AuraIdentity::<T>::setup_recovery(
+			RawOrigin::Signed(owner.clone()).into(),
+			MIN_THRESHOLD,
+			trustees.iter().cloned().map(T::TrusteeLookup::unlookup).collect(),
+			None,
+		)
+		.expect("valid recovery setup");
+		for account in &trustees {
+			AuraIdentity::<T>::accept_trusteeship(
+				RawOrigin::Signed(account.clone()).into(),
+				T::Lookup::unlookup(owner.clone()),
+			)
+			.expect("trustee was just added");
+		}
+		let policy = all_trustees_policy(&trustees.iter().cloned().map(T::TrusteeLookup::unlookup).collect::<Vec<_>>());
+		AuraIdentity::<T>::set_recovery_policy(RawOrigin::Signed(owner.clone()).into(), Some(policy))
+			.expect("policy covers the trustees");
+
+		AuraIdentity::<T>::initiate_recovery(
+			RawOrigin::Signed(funded_account::<T>("rescuer", i + 1)).into(),
+			T::Lookup::unlookup(owner.clone()),
+			AuraPublicKey::Sr25519([7u8; 32]),
+		)
+		.expect("recovery is configured");
+		AuraIdentity::<T>::submit_recovery_share(
+			RawOrigin::Signed(trustee.clone()).into(),
+			T::Lookup::unlookup(owner),
+		)
+		.expect("trustee is confirmed");
+	}
+}
+
+/// Политика «все контакты из `trustees`»: листья и взвешенный порог над ними
+fn all_trustees_policy<A: Clone>(trustees: &[A]) -> Vec<PolicyNode<A>> {
+	let mut nodes: Vec<PolicyNode<A>> = trustees.iter().cloned().map(PolicyNode::Trustee).collect();
+	let children = (0..trustees.len() as u8).map(|index| (index, 1)).collect::<Vec<_>>();
+	nodes.push(PolicyNode::AtLeast {
+		threshold: trustees.len() as u8,
+		children: children.try_into().expect("policy fits the bound"),
+	});
+	nodes
+}
+
+/// Коэффициенты многочлена степени `t - 1` для разделения секрета владельца
+fn share_polynomial<T: Config>(owner: &T::AccountId, t: u32) -> Vec<p256::Scalar> {
+	(0..t).map(|j| vss::dealer::scalar((owner, j))).collect()
+}
+
+/// Публикует шары максимального размера для всех `trustees`
+fn upload_shares_of<T: Config>(owner: &T::AccountId, trustees: &[T::AccountId], coefficients: &[p256::Scalar]) {
+	AuraIdentity::<T>::upload_shares(
+		RawOrigin::Signed(owner.clone()).into(),
+		vss::dealer::commitments(coefficients),
+		trustees
+			.iter()
+			.map(|trustee| (T::TrusteeLookup::unlookup(trustee.clone()), vec![0u8; 1024]))
+			.collect(),
+	)
+	.expect("shares match the recovery config");
+}
+
+/// Владелец с `t` контактами, порогом `t` и опубликованными шарами.
+/// Наибольший индекс - у последнего контакта.
+fn setup_shares<T: Config>(t: u32) -> (T::AccountId, Vec<T::AccountId>, Vec<p256::Scalar>) {
+	let (owner, trustees) = setup_trustees_with_threshold::<T>(t, t as u8, true);
+	let coefficients = share_polynomial::<T>(&owner, t);
+	upload_shares_of::<T>(&owner, &trustees, &coefficients);
+	(owner, trustees, coefficients)
+}
+
+/// Уникальный фрагмент максимальной длины
+fn fragment(index: u32) -> Vec<u8> {
+	let mut fragment = vec![b'f'; MAX_FRAGMENT_LENGTH as usize];
+	fragment[..4].copy_from_slice(&index.to_le_bytes());
+	fragment
+}
+
+fn add_verification_method_of<T: Config>(who: &T::AccountId, index: u32, controller: [u8; 32]) {
+	AuraIdentity::<T>::add_verification_method(
+		RawOrigin::Signed(who.clone()).into(),
+		fragment(index),
+		VerificationKeyType::EcdsaSecp256k1,
+		vec![1u8; MAX_METHOD_KEY_LENGTH as usize],
+		Some(T::DidLookup::unlookup(controller)),
+		vec![
+			VerificationPurpose::Authentication,
+			VerificationPurpose::AssertionMethod,
+			VerificationPurpose::KeyAgreement,
+			VerificationPurpose::CapabilityInvocation,
+			VerificationPurpose::CapabilityDelegation,
+		],
+	)
+	.expect("document has room for the method");
+}
+
+fn add_service_of<T: Config>(who: &T::AccountId, index: u32) {
+	AuraIdentity::<T>::add_service(
+		RawOrigin::Signed(who.clone()).into(),
+		fragment(index),
+		vec![b't'; MAX_SERVICE_TYPE_LENGTH as usize],
+		vec![b'e'; MAX_SERVICE_ENDPOINT_LENGTH as usize],
+	)
+	.expect("document has room for the service");
+}
+
+/// Aura ID другого аккаунта, который можно указать контроллером
+fn controller_did<T: Config>(index: u32) -> [u8; 32] {
+	let controller = funded_account::<T>("controller", index);
+	create_identity::<T>(&controller);
+	AuraIdentities::<T>::get(&controller).expect("identity was just created").did
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_aura_id(c: Linear<0, 1024>) {
+		let caller = funded_account::<T>("caller", 0);
+		let (public_key, proof) = key_proof::<T>(&caller);
+		let recovery_config = vec![0u8; c as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), public_key, recovery_config, proof);
+
+		assert!(AuraIdentities::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn setup_recovery(t: Linear<2, MAX_TRUSTEES>) {
+		let caller = funded_account::<T>("caller", 0);
+		create_identity::<T>(&caller);
+		let trustees: Vec<TrusteeLookupOf<T>> = (0..t)
+			.map(|i| {
+				let trustee = funded_account::<T>("trustee", i);
+				create_identity::<T>(&trustee);
+				T::TrusteeLookup::unlookup(trustee)
+			})
+			.collect();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), MIN_THRESHOLD, trustees, None);
+
+		assert_last_event::<T>(
+			Event::<T>::RecoveryConfigured { account: caller, threshold: MIN_THRESHOLD, total_trustees: t as u8 }
+				.into(),
+		);
+	}
+
+	#[benchmark]
+	fn add_trustee() {
+		let (owner, _) = setup_trustees::<T>(MIN_THRESHOLD as u32, false);
+		let trustee = funded_account::<T>("new_trustee", 0);
+		create_identity::<T>(&trustee);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), T::TrusteeLookup::unlookup(trustee.clone()));
+
+		assert!(TrusteeShares::<T>::contains_key(&owner, &trustee));
+	}
+
+	#[benchmark]
+	fn remove_trustee() {
+		let (owner, trustees) = setup_trustees::<T>(MIN_THRESHOLD as u32 + 1, false);
+		let trustee = trustees[0].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), T::TrusteeLookup::unlookup(trustee.clone()));
+
+		assert!(!TrusteeShares::<T>::contains_key(&owner, &trustee));
+	}
+
+	#[benchmark]
+	fn initiate_recovery() {
+		let (owner, _) = setup_trustees::<T>(MIN_THRESHOLD as u32, true);
+		let rescuer = funded_account::<T>("rescuer", 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(rescuer), T::Lookup::unlookup(owner.clone()), AuraPublicKey::Sr25519([7u8; 32]));
+
+		assert!(ActiveRecoveries::<T>::contains_key(&owner));
+	}
+
+	#[benchmark]
+	fn submit_recovery_share() -> Result<(), BenchmarkError> {
+		let (owner, trustees) = setup_trustees::<T>(MIN_THRESHOLD as u32, true);
+		let rescuer = funded_account::<T>("rescuer", 0);
+		AuraIdentity::<T>::initiate_recovery(
+			RawOrigin::Signed(rescuer).into(),
+			T::Lookup::unlookup(owner.clone()),
+			AuraPublicKey::Sr25519([7u8; 32]),
+		)?;
+		let trustee = trustees[0].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(trustee.clone()), T::Lookup::unlookup(owner.clone()));
+
+		assert!(RecoveryVouches::<T>::contains_key(&owner, &trustee));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_recovery(t: Linear<2, MAX_TRUSTEES>) {
+		let (owner, rescuer) = setup_vouched_recovery::<T>(t);
+		// Худший случай: политика проверяет подтверждение каждого контакта
+		let trustees = TrusteeShares::<T>::iter_key_prefix(&owner).collect::<Vec<_>>();
+		RecoveryPolicies::<T>::insert(
+			&owner,
+			RecoveryPolicy { nodes: all_trustees_policy(&trustees).try_into().expect("policy fits the bound") },
+		);
+		let execute_at = ActiveRecoveries::<T>::get(&owner).expect("recovery is active").execute_at;
+		frame_system::Pallet::<T>::set_block_number(execute_at);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(rescuer), T::Lookup::unlookup(owner.clone()));
+
+		assert_eq!(
+			AuraIdentities::<T>::get(&owner).map(|record| record.public_key),
+			Some(AuraPublicKey::Sr25519([7u8; 32]))
+		);
+	}
+
+	#[benchmark]
+	fn cancel_recovery(t: Linear<2, MAX_TRUSTEES>) {
+		let (owner, _) = setup_vouched_recovery::<T>(t);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()));
+
+		assert!(!ActiveRecoveries::<T>::contains_key(&owner));
+	}
+
+	// Худший случай: очистка подтверждений всех `t` контактов
+	#[benchmark]
+	fn remove_expired_recovery(t: Linear<2, MAX_TRUSTEES>) {
+		let (owner, _) = setup_vouched_recovery::<T>(t);
+		let request = ActiveRecoveries::<T>::get(&owner).expect("recovery is active");
+		frame_system::Pallet::<T>::set_block_number(AuraIdentity::<T>::recovery_expires_at(&request));
+		let caller = funded_account::<T>("caller", 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(owner.clone()));
+
+		assert!(!ActiveRecoveries::<T>::contains_key(&owner));
+	}
+
+	#[benchmark]
+	fn accept_trusteeship() {
+		let (owner, trustees) = setup_trustees::<T>(MIN_THRESHOLD as u32, false);
+		let trustee = trustees[0].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(trustee.clone()), T::Lookup::unlookup(owner.clone()));
+
+		assert!(TrusteeShares::<T>::get(&owner, &trustee).map_or(false, |share| share.confirmed));
+	}
+
+	#[benchmark]
+	fn decline_trusteeship() {
+		// Худший случай: контакт поддержал запрос, который уже истек
+		let (owner, _) = setup_vouched_recovery::<T>(MIN_THRESHOLD as u32 + 1);
+		let request = ActiveRecoveries::<T>::get(&owner).expect("recovery is active");
+		frame_system::Pallet::<T>::set_block_number(AuraIdentity::<T>::recovery_expires_at(&request));
+		let trustee: T::AccountId = account("trustee", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(trustee.clone()), T::Lookup::unlookup(owner.clone()));
+
+		assert!(!TrusteeShares::<T>::contains_key(&owner, &trustee));
+	}
+
+	#[benchmark]
+	fn update_recovery_config() {
+		let (owner, _) = setup_trustees::<T>(MIN_THRESHOLD as u32, false);
+		let delay_period = T::MinRecoveryDelay::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), delay_period);
+
+		assert!(PendingDelayUpdates::<T>::contains_key(&owner));
+	}
+
+	#[benchmark]
+	fn apply_recovery_config_update() -> Result<(), BenchmarkError> {
+		let (owner, _) = setup_trustees::<T>(MIN_THRESHOLD as u32, false);
+		let delay_period = T::MinRecoveryDelay::get();
+		AuraIdentity::<T>::update_recovery_config(RawOrigin::Signed(owner.clone()).into(), delay_period)?;
+		let apply_at = PendingDelayUpdates::<T>::get(&owner).expect("update was just scheduled").apply_at;
+		frame_system::Pallet::<T>::set_block_number(apply_at);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()));
+
+		assert_eq!(RecoveryConfigs::<T>::get(&owner).map(|config| config.delay_period), Some(delay_period));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_recovery(t: Linear<2, MAX_TRUSTEES>) {
+		let (owner, _) = setup_trustees::<T>(t, true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()));
+
+		assert!(!RecoveryConfigs::<T>::contains_key(&owner));
+	}
+
+	#[benchmark]
+	fn rotate_key() -> Result<(), BenchmarkError> {
+		let caller = funded_account::<T>("caller", 0);
+		create_identity::<T>(&caller);
+		// Худший случай: история ключей заполнена и самый старый ключ вытесняется
+		for i in 0..T::MaxKeyHistory::get() {
+			frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(i + 2));
+			AuraIdentity::<T>::replace_key(&caller, AuraPublicKey::Sr25519([(i % 255) as u8 + 1; 32]))?;
+		}
+		let nonce = KeyProofNonces::<T>::get(&caller);
+		let (new_public_key, proof) = key_proof::<T>(&caller);
+		// Текущий ключ, которым подписано разрешение на смену
+		let payload = AuraIdentity::<T>::key_rotation_payload(&caller, &new_public_key, nonce);
+		let (current_key, authorization) = p256_sign((b"current", &caller), &payload);
+		AuraIdentity::<T>::replace_key(&caller, current_key)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), new_public_key, proof, authorization);
+
+		assert_eq!(AuraIdentities::<T>::get(&caller).map(|record| record.public_key), Some(new_public_key));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_verification_method() {
+		let caller = funded_account::<T>("caller", 0);
+		create_identity::<T>(&caller);
+		let controller = controller_did::<T>(0);
+		// Худший случай: документ заполнен до последнего свободного места
+		let existing = T::MaxVerificationMethods::get().saturating_sub(1);
+		for i in 0..existing {
+			add_verification_method_of::<T>(&caller, i, controller);
+		}
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			fragment(existing),
+			VerificationKeyType::EcdsaSecp256k1,
+			vec![1u8; MAX_METHOD_KEY_LENGTH as usize],
+			Some(T::DidLookup::unlookup(controller)),
+			vec![
+				VerificationPurpose::Authentication,
+				VerificationPurpose::AssertionMethod,
+				VerificationPurpose::KeyAgreement,
+				VerificationPurpose::CapabilityInvocation,
+				VerificationPurpose::CapabilityDelegation,
+			],
+		);
+
+		assert_eq!(VerificationMethods::<T>::iter_prefix(&caller).count() as u32, existing + 1);
+	}
+
+	#[benchmark]
+	fn remove_verification_method() {
+		let caller = funded_account::<T>("caller", 0);
+		create_identity::<T>(&caller);
+		add_verification_method_of::<T>(&caller, 0, controller_did::<T>(0));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), fragment(0));
+
+		assert_eq!(VerificationMethods::<T>::iter_prefix(&caller).count(), 0);
+	}
+
+	#[benchmark]
+	fn add_service() {
+		let caller = funded_account::<T>("caller", 0);
+		create_identity::<T>(&caller);
+		let existing = T::MaxServices::get().saturating_sub(1);
+		for i in 0..existing {
+			add_service_of::<T>(&caller, i);
+		}
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			fragment(existing),
+			vec![b't'; MAX_SERVICE_TYPE_LENGTH as usize],
+			vec![b'e'; MAX_SERVICE_ENDPOINT_LENGTH as usize],
+		);
+
+		assert_eq!(Services::<T>::iter_prefix(&caller).count() as u32, existing + 1);
+	}
+
+	#[benchmark]
+	fn remove_service() {
+		let caller = funded_account::<T>("caller", 0);
+		create_identity::<T>(&caller);
+		add_service_of::<T>(&caller, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), fragment(0));
+
+		assert_eq!(Services::<T>::iter_prefix(&caller).count(), 0);
+	}
+
+	#[benchmark]
+	fn add_controller() -> Result<(), BenchmarkError> {
+		let caller = funded_account::<T>("caller", 0);
+		create_identity::<T>(&caller);
+		let existing = T::MaxControllers::get().saturating_sub(1);
+		for i in 0..existing {
+			AuraIdentity::<T>::add_controller(
+				RawOrigin::Signed(caller.clone()).into(),
+				T::DidLookup::unlookup(controller_did::<T>(i)),
+			)?;
+		}
+		let controller = controller_did::<T>(existing);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::DidLookup::unlookup(controller));
+
+		assert!(Controllers::<T>::contains_key(&caller, controller));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_controller() -> Result<(), BenchmarkError> {
+		let caller = funded_account::<T>("caller", 0);
+		create_identity::<T>(&caller);
+		let controller = controller_did::<T>(0);
+		AuraIdentity::<T>::add_controller(RawOrigin::Signed(caller.clone()).into(), T::DidLookup::unlookup(controller))?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::DidLookup::unlookup(controller));
+
+		assert!(!Controllers::<T>::contains_key(&caller, controller));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deactivate_aura_id() {
+		// Худший случай: активное восстановление, полностью заполненный DID
+		// документ и подтвержденные запросы владельцев, у которых аккаунт -
+		// доверенный контакт
+		let (owner, _) = setup_vouched_recovery::<T>(MAX_TRUSTEES);
+		setup_trusteeships::<T>(&owner);
+		let controller = controller_did::<T>(0);
+		for i in 0..T::MaxVerificationMethods::get() {
+			add_verification_method_of::<T>(&owner, i, controller);
+		}
+		for i in 0..T::MaxServices::get() {
+			add_service_of::<T>(&owner, i);
+		}
+		for i in 0..T::MaxControllers::get() {
+			let controller = controller_did::<T>(i + 1);
+			AuraIdentity::<T>::add_controller(
+				RawOrigin::Signed(owner.clone()).into(),
+				T::DidLookup::unlookup(controller),
+			)
+			.expect("document has room for the controller");
+		}
+		let did = AuraIdentities::<T>::get(&owner).expect("owner has an Aura ID").did;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()));
+
+		assert!(Tombstones::<T>::contains_key(did));
+		assert_eq!(Trusteeships::<T>::iter_prefix(&owner).count(), 0);
+	}
+
+	#[benchmark]
+	fn set_recovery_policy(n: Linear<3, MAX_POLICY_NODES>) {
+		// Листья - контакты, над ними порог; оставшиеся узлы - обертки `Any`
+		let leaves = (n - 1).min(MAX_TRUSTEES);
+		let (owner, trustees) = setup_trustees::<T>(leaves, true);
+		let mut nodes = all_trustees_policy(
+			&trustees.into_iter().map(T::TrusteeLookup::unlookup).collect::<Vec<_>>(),
+		);
+		while (nodes.len() as u32) < n {
+			let root = (nodes.len() - 1) as u8;
+			nodes.push(PolicyNode::Any(vec![root].try_into().expect("single child fits the bound")));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), Some(nodes));
+
+		assert_eq!(RecoveryPolicies::<T>::get(&owner).map(|policy| policy.nodes.len() as u32), Some(n));
+	}
+
+	#[benchmark]
+	fn set_backup_key() {
+		let owner = funded_account::<T>("owner", 0);
+		create_identity::<T>(&owner);
+		let (public_key, proof) = key_proof::<T>(&owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), public_key, proof, None);
+
+		assert_eq!(BackupKeys::<T>::get(&owner).map(|backup| backup.public_key), Some(public_key));
+	}
+
+	#[benchmark]
+	fn remove_backup_key() {
+		let owner = funded_account::<T>("owner", 0);
+		create_identity::<T>(&owner);
+		let (public_key, proof) = key_proof::<T>(&owner);
+		AuraIdentity::<T>::set_backup_key(RawOrigin::Signed(owner.clone()).into(), public_key, proof, None)
+			.expect("owner has an Aura ID");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()));
+
+		assert!(!BackupKeys::<T>::contains_key(&owner));
+	}
+
+	#[benchmark]
+	fn initiate_backup_recovery() {
+		let owner = funded_account::<T>("owner", 0);
+		create_identity::<T>(&owner);
+		let nonce = KeyProofNonces::<T>::get(&owner);
+		let (public_key, proof) = key_proof::<T>(&owner);
+		AuraIdentity::<T>::set_backup_key(RawOrigin::Signed(owner.clone()).into(), public_key, proof, None)
+			.expect("owner has an Aura ID");
+
+		let rescuer = funded_account::<T>("rescuer", 0);
+		let new_public_key = AuraPublicKey::Sr25519([7u8; 32]);
+		let payload = AuraIdentity::<T>::backup_recovery_payload(
+			&owner,
+			&new_public_key,
+			&rescuer,
+			KeyProofNonces::<T>::get(&owner),
+		);
+		// Ключ, зарегистрированный выше как резервный
+		let (_, signature) = p256_sign((&owner, nonce), &payload);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(rescuer), T::Lookup::unlookup(owner.clone()), new_public_key, signature);
+
+		assert!(BackupApprovals::<T>::contains_key(&owner));
+	}
+
+	#[benchmark]
+	fn upload_shares(t: Linear<2, MAX_TRUSTEES>) {
+		// Худший случай: предыдущие шары сбрасываются
+		let (owner, trustees, coefficients) = setup_shares::<T>(t);
+		let shares = trustees
+			.iter()
+			.map(|trustee| (T::TrusteeLookup::unlookup(trustee.clone()), vec![1u8; 1024]))
+			.collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), vss::dealer::commitments(&coefficients), shares);
+
+		assert_eq!(ShareStates::<T>::iter_prefix(&owner).count() as u32, t);
+	}
+
+	#[benchmark]
+	fn prove_share(t: Linear<2, MAX_TRUSTEES>) {
+		let (owner, trustees, coefficients) = setup_shares::<T>(t);
+		let trustee = trustees[t as usize - 1].clone();
+		let context = AuraIdentity::<T>::share_proof_context(&owner, &trustee, t as u8);
+		let proof = vss::dealer::prove(&vss::dealer::share(&coefficients, t as u8), &context);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(trustee.clone()), T::Lookup::unlookup(owner.clone()), proof);
+
+		assert_eq!(ShareStates::<T>::get(&owner, &trustee).map(|info| info.status), Some(ShareStatus::Verified));
+	}
+
+	#[benchmark]
+	fn dispute_share() {
+		let (owner, trustees, _) = setup_shares::<T>(MIN_THRESHOLD as u32);
+		let trustee = trustees[0].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(trustee.clone()), T::Lookup::unlookup(owner.clone()));
+
+		assert!(matches!(
+			ShareStates::<T>::get(&owner, &trustee).map(|info| info.status),
+			Some(ShareStatus::Disputed { .. })
+		));
+	}
+
+	#[benchmark]
+	fn reveal_share(t: Linear<2, MAX_TRUSTEES>) {
+		let (owner, trustees, coefficients) = setup_shares::<T>(t);
+		let trustee = trustees[t as usize - 1].clone();
+		AuraIdentity::<T>::dispute_share(RawOrigin::Signed(trustee.clone()).into(), T::Lookup::unlookup(owner.clone()))
+			.expect("share is pending");
+		let share = vss::dealer::share_bytes(&vss::dealer::share(&coefficients, t as u8));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), T::TrusteeLookup::unlookup(trustee.clone()), share);
+
+		assert_eq!(ShareStates::<T>::get(&owner, &trustee).map(|info| info.status), Some(ShareStatus::Verified));
+	}
+
+	impl_benchmark_test_suite!(AuraIdentity, crate::tests::new_test_ext(), crate::tests::Test);
+}
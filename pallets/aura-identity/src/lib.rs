@@ -2,24 +2,70 @@
 
 pub use pallet::*;
 
+/// Цель логирования паллета
+pub(crate) const LOG_TARGET: &str = "runtime::aura-identity";
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod vss;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*, 
-		traits::{Currency, ExistenceRequirement, ReservableCurrency},
+		traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency},
 		Blake2_128Concat, BoundedVec
 	};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{StaticLookup, Zero},
+		Perbill,
+	};
 	use sp_std::vec::Vec;
 	use scale_info::TypeInfo;
+	use crate::vss::{self, ShareCommitment, ShareProof, ShareScalar};
+	use crate::weights::WeightInfo;
 
 	// Константы для Social Recovery
 	pub const MAX_TRUSTEES: u32 = 10;
 	pub const MIN_THRESHOLD: u8 = 2;
 	pub const MAX_THRESHOLD: u8 = 10;
-	pub const DEFAULT_RECOVERY_DELAY: u32 = 14400; // ~24 часа
+
+	/// Верхняя граница запросов на восстановление, проверяемых за блок в `on_idle`
+	pub const MAX_RECOVERY_SWEEP: u32 = 100;
+
+	/// Максимальное количество узлов в политике восстановления
+	pub const MAX_POLICY_NODES: u32 = 16;
+
+	/// Доменный разделитель для доказательства владения ключом
+	pub const KEY_PROOF_CONTEXT: &[u8] = b"aura-identity:key-proof";
+
+	/// Доменный разделитель для разрешения смены ключа текущим ключом
+	pub const KEY_ROTATION_CONTEXT: &[u8] = b"aura-identity:key-rotation";
+
+	/// Доменный разделитель для восстановления резервным ключом
+	pub const BACKUP_RECOVERY_CONTEXT: &[u8] = b"aura-identity:backup-recovery";
+
+	/// Доменный разделитель для доказательства знания шара секрета
+	pub const SHARE_PROOF_CONTEXT: &[u8] = b"aura-identity:share-proof";
+
+	/// Доменный разделитель и версия схемы получения DID
+	pub const DID_DERIVATION_CONTEXT: &[u8] = b"did:aura:v2";
+
+	// Ограничения элементов DID документа
+	pub const MAX_FRAGMENT_LENGTH: u32 = 32;
+	pub const MAX_METHOD_KEY_LENGTH: u32 = 65;
+	pub const MAX_SERVICE_TYPE_LENGTH: u32 = 64;
+	pub const MAX_SERVICE_ENDPOINT_LENGTH: u32 = 256;
+
+	/// Текущая версия хранилища паллета
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -33,28 +79,440 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxTrustees: Get<u32>;
 		
+		/// У скольких аккаунтов один аккаунт может быть доверенным контактом
+		#[pallet::constant]
+		type MaxTrusteeships: Get<u32>;
+		
 		/// Депозит для настройки recovery
 		#[pallet::constant]
 		type RecoveryDeposit: Get<BalanceOf<Self>>;
+		
+		/// Залог инициатора восстановления
+		#[pallet::constant]
+		type RecoveryBond: Get<BalanceOf<Self>>;
+		
+		/// Доля залога, которая списывается, если владелец отменил восстановление
+		#[pallet::constant]
+		type RecoveryBondSlash: Get<Perbill>;
+		
+		/// Получатель списанного залога (казна). Если `None` - залог получает владелец
+		type SlashBeneficiary: Get<Option<Self::AccountId>>;
+		
+		/// Период ожидания восстановления по умолчанию (блоки)
+		#[pallet::constant]
+		type DefaultRecoveryDelay: Get<u32>;
+		
+		/// Минимальный период ожидания восстановления (блоки)
+		#[pallet::constant]
+		type MinRecoveryDelay: Get<u32>;
+		
+		/// Максимальный период ожидания восстановления (блоки)
+		#[pallet::constant]
+		type MaxRecoveryDelay: Get<u32>;
+		
+		/// Сколько блоков после периода ожидания запрос на восстановление
+		/// остается в силе. Затем запрос истекает, а залог возвращается.
+		#[pallet::constant]
+		type RecoveryLifetime: Get<u32>;
+		
+		/// Сколько блоков у владельца есть на ответ на жалобу доверенного
+		/// контакта на шар секрета. Без ответа шар считается некорректным.
+		#[pallet::constant]
+		type ShareComplaintPeriod: Get<u32>;
+		
+		/// Сколько предыдущих ключей хранится в истории Aura ID
+		#[pallet::constant]
+		type MaxKeyHistory: Get<u32>;
+		
+		/// Максимальное количество verification methods в DID документе
+		#[pallet::constant]
+		type MaxVerificationMethods: Get<u32>;
+		
+		/// Максимальное количество service endpoints в DID документе
+		#[pallet::constant]
+		type MaxServices: Get<u32>;
+		
+		/// Максимальное количество контроллеров DID документа
+		#[pallet::constant]
+		type MaxControllers: Get<u32>;
+		
+		/// Депозит за каждый элемент DID документа
+		#[pallet::constant]
+		type DocumentDepositBase: Get<BalanceOf<Self>>;
+		
+		/// Депозит за каждый байт элемента DID документа
+		#[pallet::constant]
+		type DocumentByteDeposit: Get<BalanceOf<Self>>;
+		
+		/// Origin, который может принудительно деактивировать Aura ID (root или governance)
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		
+		/// Поиск DID по аргументу вызова: сам DID или, например, имя из реестра имен.
+		type DidLookup: StaticLookup<Target = [u8; 32]>;
+		
+		/// Поиск доверенного контакта по аргументу вызова: аккаунт или, например, имя
+		/// из реестра имен. Остальные аккаунты ищутся через `frame_system::Config::Lookup`.
+		type TrusteeLookup: StaticLookup<Target = Self::AccountId>;
+		
+		/// Веса экстринсиков паллета
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Тип для баланса
-	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Аккаунт в аргументах вызовов
+	pub type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+	/// DID в аргументах вызовов
+	pub type DidLookupOf<T> = <<T as Config>::DidLookup as StaticLookup>::Source;
+
+	/// Доверенный контакт в аргументах вызовов
+	pub type TrusteeLookupOf<T> = <<T as Config>::TrusteeLookup as StaticLookup>::Source;
 
 	// ========== СУЩЕСТВУЮЩИЕ СТРУКТУРЫ ==========
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_aura_id)]
-	pub type AuraIdentities<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, AuraIdRecord>;
+	pub type AuraIdentities<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, AuraIdRecord<BlockNumberFor<T>>>;
 
 	#[pallet::storage]
 	pub type DidIndex<T: Config> = StorageMap<_, Blake2_128Concat, [u8; 32], T::AccountId>;
 
+	/// Курсор многоблочной миграции: сырой ключ последней перенесенной записи.
+	/// Пока значение существует, миграция не завершена.
+	#[pallet::storage]
+	#[pallet::getter(fn migration_cursor)]
+	pub type MigrationCursor<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<256>>>;
+
+	// ========== КЛЮЧИ AURA ID ==========
+
+	/// Публичный ключ Aura ID с явным алгоритмом подписи
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum AuraPublicKey {
+		/// Schnorr/Ristretto (sr25519), 32 байта
+		Sr25519([u8; 32]),
+		/// Ed25519, 32 байта
+		Ed25519([u8; 32]),
+		/// ECDSA secp256k1, сжатый ключ (33 байта)
+		EcdsaSecp256k1([u8; 33]),
+		/// ECDSA P-256 (secp256r1), сжатый ключ (33 байта)
+		P256([u8; 33]),
+		/// Ключ, перенесенный из версии 0 хранилища без алгоритма: ключ sr25519,
+		/// ключ ed25519 или blake2_256 сжатого ключа ecdsa. Не публикуется в DID
+		/// документе и не подтверждает подписи - им можно только разрешить
+		/// замену через `rotate_key` (см. `verify_rotation`).
+		Legacy([u8; 32]),
+	}
+
+	/// Подпись ключом Aura ID. Вариант должен совпадать с алгоритмом ключа.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum AuraSignature {
+		Sr25519([u8; 64]),
+		Ed25519([u8; 64]),
+		/// `r || s || v`, подписывается blake2_256 сообщения (как в `sp_core::ecdsa`)
+		EcdsaSecp256k1([u8; 65]),
+		/// `r || s`, подписывается SHA-256 сообщения (как в Secure Enclave и WebAuthn)
+		P256([u8; 64]),
+	}
+
+	impl AuraPublicKey {
+		/// Тип ключа для verification method DID документа. `None` для
+		/// ключа версии 0: его алгоритм неизвестен.
+		pub fn key_type(&self) -> Option<VerificationKeyType> {
+			match self {
+				Self::Sr25519(_) => Some(VerificationKeyType::Sr25519),
+				Self::Ed25519(_) => Some(VerificationKeyType::Ed25519),
+				Self::EcdsaSecp256k1(_) => Some(VerificationKeyType::EcdsaSecp256k1),
+				Self::P256(_) => Some(VerificationKeyType::P256),
+				Self::Legacy(_) => None,
+			}
+		}
+
+		/// Ключ перенесен из версии 0 и должен быть заменен через `rotate_key`
+		pub fn is_legacy(&self) -> bool {
+			matches!(self, Self::Legacy(_))
+		}
+
+		/// Байты ключа без алгоритма
+		pub fn as_bytes(&self) -> &[u8] {
+			match self {
+				Self::Sr25519(key) | Self::Ed25519(key) | Self::Legacy(key) => &key[..],
+				Self::EcdsaSecp256k1(key) | Self::P256(key) => &key[..],
+			}
+		}
+
+		/// Проверяет подпись, разрешающую замену ключа. Для ключа версии 0
+		/// подходит подпись sr25519 или ed25519 этими байтами либо подпись
+		/// ecdsa ключом, blake2_256 которого они равны. Для остальных ключей -
+		/// то же, что `verify`.
+		pub fn verify_rotation(&self, message: &[u8], signature: &AuraSignature) -> bool {
+			let Self::Legacy(key) = self else {
+				return self.verify(message, signature);
+			};
+			match signature {
+				AuraSignature::Sr25519(_) => Self::Sr25519(*key).verify(message, signature),
+				AuraSignature::Ed25519(_) => Self::Ed25519(*key).verify(message, signature),
+				AuraSignature::EcdsaSecp256k1(signature) => {
+					let hash = sp_io::hashing::blake2_256(message);
+					sp_io::crypto::secp256k1_ecdsa_recover_compressed(signature, &hash)
+						.map_or(false, |recovered| sp_io::hashing::blake2_256(&recovered) == *key)
+				},
+				AuraSignature::P256(_) => false,
+			}
+		}
+
+		/// Проверяет подпись `signature` над `message`. Подпись другого
+		/// алгоритма недействительна.
+		pub fn verify(&self, message: &[u8], signature: &AuraSignature) -> bool {
+			match (self, signature) {
+				(Self::Sr25519(key), AuraSignature::Sr25519(signature)) => sp_io::crypto::sr25519_verify(
+					&sp_core::sr25519::Signature(*signature),
+					message,
+					&sp_core::sr25519::Public(*key),
+				),
+				(Self::Ed25519(key), AuraSignature::Ed25519(signature)) => sp_io::crypto::ed25519_verify(
+					&sp_core::ed25519::Signature(*signature),
+					message,
+					&sp_core::ed25519::Public(*key),
+				),
+				(Self::EcdsaSecp256k1(key), AuraSignature::EcdsaSecp256k1(signature)) => {
+					let hash = sp_io::hashing::blake2_256(message);
+					sp_io::crypto::secp256k1_ecdsa_recover_compressed(signature, &hash)
+						.map_or(false, |recovered| recovered == *key)
+				},
+				(Self::P256(key), AuraSignature::P256(signature)) => {
+					use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+					// Для P-256 нет host-функции, проверка выполняется в runtime
+					match (VerifyingKey::from_sec1_bytes(&key[..]), Signature::from_slice(&signature[..])) {
+						(Ok(key), Ok(signature)) => key.verify(message, &signature).is_ok(),
+						_ => false,
+					}
+				},
+				_ => false,
+			}
+		}
+	}
+
+	impl From<sp_core::sr25519::Public> for AuraPublicKey {
+		fn from(key: sp_core::sr25519::Public) -> Self {
+			Self::Sr25519(key.0)
+		}
+	}
+
+	impl From<sp_core::ed25519::Public> for AuraPublicKey {
+		fn from(key: sp_core::ed25519::Public) -> Self {
+			Self::Ed25519(key.0)
+		}
+	}
+
+	impl From<sp_core::ecdsa::Public> for AuraPublicKey {
+		fn from(key: sp_core::ecdsa::Public) -> Self {
+			Self::EcdsaSecp256k1(key.0)
+		}
+	}
+
+	impl From<sp_core::sr25519::Signature> for AuraSignature {
+		fn from(signature: sp_core::sr25519::Signature) -> Self {
+			Self::Sr25519(signature.0)
+		}
+	}
+
+	impl From<sp_core::ed25519::Signature> for AuraSignature {
+		fn from(signature: sp_core::ed25519::Signature) -> Self {
+			Self::Ed25519(signature.0)
+		}
+	}
+
+	impl From<sp_core::ecdsa::Signature> for AuraSignature {
+		fn from(signature: sp_core::ecdsa::Signature) -> Self {
+			Self::EcdsaSecp256k1(signature.0)
+		}
+	}
+
+	/// Предыдущий ключ Aura ID и интервал блоков, в котором он действовал
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct KeyRecord<BlockNumber> {
+		/// Публичный ключ
+		pub public_key: AuraPublicKey,
+		/// Первый блок, в котором ключ действовал
+		pub valid_from: BlockNumber,
+		/// Блок, начиная с которого ключ больше не действует
+		pub valid_until: BlockNumber,
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_history)]
+	/// История ключей Aura ID (от старых к новым)
+	pub type KeyHistory<T: Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		BoundedVec<KeyRecord<BlockNumberFor<T>>, T::MaxKeyHistory>,
+		ValueQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn key_proof_nonce)]
+	/// Счетчик доказательств владения ключом (защита от повторного использования подписи)
+	pub type KeyProofNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	// ========== DID ДОКУМЕНТ (W3C DID Core) ==========
+
+	/// Идентификатор элемента документа - фрагмент в `did:aura:<id>#<fragment>`
+	pub type Fragment = BoundedVec<u8, ConstU32<MAX_FRAGMENT_LENGTH>>;
+
+	/// Назначение verification method (verification relationship)
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum VerificationPurpose {
+		Authentication,
+		AssertionMethod,
+		KeyAgreement,
+		CapabilityInvocation,
+		CapabilityDelegation,
+	}
+
+	/// Тип ключа verification method
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum VerificationKeyType {
+		Sr25519,
+		Ed25519,
+		EcdsaSecp256k1,
+		X25519,
+		P256,
+	}
+
+	/// Verification method DID документа
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct VerificationMethod<Balance> {
+		/// Тип ключа
+		pub key_type: VerificationKeyType,
+		/// Публичный ключ
+		pub public_key: BoundedVec<u8, ConstU32<MAX_METHOD_KEY_LENGTH>>,
+		/// DID контроллера ключа (`None` - сам владелец документа)
+		pub controller: Option<[u8; 32]>,
+		/// Для чего используется ключ
+		pub purposes: BoundedVec<VerificationPurpose, ConstU32<5>>,
+		/// Депозит за хранение
+		pub deposit: Balance,
+	}
+
+	/// Service endpoint DID документа
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ServiceEndpoint<Balance> {
+		/// Тип сервиса (например, `LinkedDomains`)
+		pub service_type: BoundedVec<u8, ConstU32<MAX_SERVICE_TYPE_LENGTH>>,
+		/// URI сервиса
+		pub endpoint: BoundedVec<u8, ConstU32<MAX_SERVICE_ENDPOINT_LENGTH>>,
+		/// Депозит за хранение
+		pub deposit: Balance,
+	}
+
+	/// Версия DID документа для метаданных разрешения
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+	pub struct DocumentVersion<BlockNumber> {
+		/// Номер версии, увеличивается при каждом изменении
+		pub version_id: u32,
+		/// Блок последнего изменения
+		pub updated: BlockNumber,
+	}
+
+	/// Содержимое DID документа, прочитанное в одном блоке (для runtime API)
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct DidDocument<Balance, BlockNumber> {
+		/// Aura ID, которому принадлежит DID
+		pub record: AuraIdRecord<BlockNumber>,
+		/// Дополнительные verification methods: фрагмент и метод
+		pub methods: Vec<(Fragment, VerificationMethod<Balance>)>,
+		/// Service endpoints: фрагмент и сервис
+		pub services: Vec<(Fragment, ServiceEndpoint<Balance>)>,
+		/// DID контроллеров
+		pub controllers: Vec<[u8; 32]>,
+		/// Версия документа
+		pub version: DocumentVersion<BlockNumber>,
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn verification_method)]
+	/// Дополнительные verification methods DID документа
+	pub type VerificationMethods<T: Config> = StorageDoubleMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		Blake2_128Concat, 
+		Fragment, 
+		VerificationMethod<BalanceOf<T>>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn service)]
+	/// Service endpoints DID документа
+	pub type Services<T: Config> = StorageDoubleMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		Blake2_128Concat, 
+		Fragment, 
+		ServiceEndpoint<BalanceOf<T>>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn controller_deposit)]
+	/// Контроллеры DID документа (DID контроллера -> депозит)
+	pub type Controllers<T: Config> = StorageDoubleMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		Blake2_128Concat, 
+		[u8; 32], 
+		BalanceOf<T>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn document_version)]
+	/// Версия и время последнего изменения DID документа
+	pub type DocumentVersions<T: Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		DocumentVersion<BlockNumberFor<T>>,
+		ValueQuery
+	>;
+
+	/// Запись о деактивированном DID
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Tombstone<AccountId, BlockNumber> {
+		/// Аккаунт, которому принадлежал DID
+		pub account: AccountId,
+		/// Блок деактивации
+		pub deactivated_at: BlockNumber,
+		/// Деактивирован принудительно (`ForceOrigin`), а не владельцем
+		pub forced: bool,
+		/// Версия DID документа, включая деактивацию
+		pub version: DocumentVersion<BlockNumber>,
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn tombstone)]
+	/// Деактивированные DID. Запись в `DidIndex` сохраняется, поэтому DID
+	/// нельзя зарегистрировать повторно.
+	pub type Tombstones<T: Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		[u8; 32], 
+		Tombstone<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery
+	>;
+
 	// ========== НОВЫЕ СТРУКТУРЫ ДЛЯ SOCIAL RECOVERY ==========
 
 	/// Конфигурация системы восстановления
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-	pub struct RecoveryConfig {
+	pub struct RecoveryConfig<Balance> {
 		/// Минимальное количество шаров для восстановления
 		pub threshold: u8,
 		/// Общее количество доверенных контактов
@@ -64,14 +522,149 @@ pub mod pallet {
 		/// Активна ли система восстановления
 		pub active: bool,
 		/// Депозит, заблокированный за настройку
-		pub deposit: BalanceOf<Self>,
+		pub deposit: Balance,
+	}
+
+	/// Ссылки узла политики на дочерние узлы (индексы в `RecoveryPolicy::nodes`)
+	pub type PolicyChildren<Child> = BoundedVec<Child, ConstU32<MAX_POLICY_NODES>>;
+
+	/// Узел политики восстановления
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum PolicyNode<AccountId> {
+		/// Доверенный контакт, выполнен, если контакт поддержал запрос
+		Trustee(AccountId),
+		/// Выполнены все дочерние узлы
+		All(PolicyChildren<u8>),
+		/// Выполнен хотя бы один дочерний узел
+		Any(PolicyChildren<u8>),
+		/// Сумма весов выполненных дочерних узлов не меньше `threshold`.
+		/// Дочерний узел задается парой `(индекс, вес)`.
+		AtLeast { threshold: u8, children: PolicyChildren<(u8, u8)> },
+	}
+
+	/// Политика восстановления - дерево условий над доверенными контактами,
+	/// например «2 из семьи И 1 из организаций» или взвешенный порог
+	/// (семья - 2, друзья - 1). Дерево хранится плоским списком: дочерние
+	/// узлы стоят раньше родителя, корень - последний узел, каждый узел
+	/// кроме корня используется ровно одним родителем.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RecoveryPolicy<AccountId> {
+		pub nodes: BoundedVec<PolicyNode<AccountId>, ConstU32<MAX_POLICY_NODES>>,
+	}
+
+	impl<AccountId: PartialEq> RecoveryPolicy<AccountId> {
+		/// Проверяет структуру дерева: ссылки только на предыдущие узлы, у
+		/// каждого узла кроме корня один родитель, контакты не повторяются,
+		/// пороги достижимы. Возвращает минимальное количество контактов,
+		/// которые должны поддержать запрос, чтобы политика была выполнена.
+		pub fn min_approvals(&self) -> Option<u32> {
+			let nodes = &self.nodes;
+			let mut has_parent = sp_std::vec![false; nodes.len()];
+			let mut min: Vec<u32> = Vec::with_capacity(nodes.len());
+			
+			for (index, node) in nodes.iter().enumerate() {
+				let mut take_child = |child: u8| -> Option<u32> {
+					let child = child as usize;
+					if child >= index || has_parent[child] {
+						return None;
+					}
+					has_parent[child] = true;
+					Some(min[child])
+				};
+				
+				let cost = match node {
+					PolicyNode::Trustee(account) => {
+						let duplicate = nodes[..index]
+							.iter()
+							.any(|other| matches!(other, PolicyNode::Trustee(other) if other == account));
+						if duplicate {
+							return None;
+						}
+						1
+					},
+					PolicyNode::All(children) | PolicyNode::Any(children) => {
+						if children.is_empty() {
+							return None;
+						}
+						let costs = children.iter().map(|child| take_child(*child)).collect::<Option<Vec<_>>>()?;
+						if matches!(node, PolicyNode::All(_)) {
+							costs.iter().sum::<u32>()
+						} else {
+							costs.into_iter().min()?
+						}
+					},
+					PolicyNode::AtLeast { threshold, children } => {
+						let total: u32 = children.iter().map(|(_, weight)| *weight as u32).sum();
+						let threshold = *threshold as usize;
+						if threshold == 0 || total < threshold as u32 || children.iter().any(|(_, weight)| *weight == 0) {
+							return None;
+						}
+						// Наименьшее число контактов, набирающее вес не меньше порога
+						// (рюкзак 0/1: `best[w]` - минимум для веса не меньше `w`)
+						let mut best = sp_std::vec![u32::MAX; threshold + 1];
+						best[0] = 0;
+						for (child, weight) in children.iter() {
+							let cost = take_child(*child)?;
+							for w in (1..=threshold).rev() {
+								let rest = best[w.saturating_sub(*weight as usize)];
+								best[w] = best[w].min(rest.saturating_add(cost));
+							}
+						}
+						best[threshold]
+					},
+				};
+				min.push(cost);
+			}
+			
+			// Все узлы кроме корня должны входить в дерево
+			let root = nodes.len().checked_sub(1)?;
+			if has_parent[..root].iter().any(|used| !used) {
+				return None;
+			}
+			min.last().copied()
+		}
+
+		/// Выполнена ли политика, если запрос поддержали контакты `approved`
+		pub fn is_satisfied(&self, approved: impl Fn(&AccountId) -> bool) -> bool {
+			let mut satisfied: Vec<bool> = Vec::with_capacity(self.nodes.len());
+			for node in self.nodes.iter() {
+				let done = |child: u8| satisfied.get(child as usize).copied().unwrap_or(false);
+				let value = match node {
+					PolicyNode::Trustee(account) => approved(account),
+					PolicyNode::All(children) => children.iter().all(|child| done(*child)),
+					PolicyNode::Any(children) => children.iter().any(|child| done(*child)),
+					PolicyNode::AtLeast { threshold, children } => children
+						.iter()
+						.filter(|(child, _)| done(*child))
+						.map(|(_, weight)| *weight as u32)
+						.sum::<u32>() >= *threshold as u32,
+				};
+				satisfied.push(value);
+			}
+			satisfied.last().copied().unwrap_or(false)
+		}
+
+		/// Корень политики - `Any`, то есть политика задает альтернативные
+		/// пути восстановления. Резервный ключ восстанавливает аккаунт в обход
+		/// политики, поэтому допустим только вместе с такой политикой.
+		pub fn allows_alternatives(&self) -> bool {
+			matches!(self.nodes.last(), Some(PolicyNode::Any(_)))
+		}
+
+		/// Контакты, упомянутые в политике
+		pub fn trustees(&self) -> impl Iterator<Item = &AccountId> {
+			self.nodes.iter().filter_map(|node| match node {
+				PolicyNode::Trustee(account) => Some(account),
+				_ => None,
+			})
+		}
 	}
 
 	/// Шар секрета для доверенного контакта
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-	pub struct TrusteeShare {
+	pub struct TrusteeShare<AccountId> {
 		/// Аккаунт доверенного контакта
-		pub trustee_account: <T as frame_system::Config>::AccountId,
+		pub trustee_account: AccountId,
 		/// Зашифрованный шар секрета
 		pub share: BoundedVec<u8, ConstU32<1024>>,
 		/// Подтвердил ли контакт участие
@@ -80,19 +673,94 @@ pub mod pallet {
 
 	/// Запрос на восстановление
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-	pub struct RecoveryRequest {
+	pub struct RecoveryRequest<AccountId, Balance, BlockNumber> {
 		/// Аккаунт инициирующий восстановление
-		pub requesting_account: <T as frame_system::Config>::AccountId,
+		pub requesting_account: AccountId,
+		/// Залог, заблокированный у инициатора
+		pub bond: Balance,
 		/// Новый публичный ключ
-		pub new_public_key: [u8; 32],
+		pub new_public_key: AuraPublicKey,
 		/// Количество собранных шаров
 		pub submitted_shares: u8,
 		/// Когда можно выполнить восстановление (номер блока)
-		pub execute_at: u32,
+		pub execute_at: BlockNumber,
 		/// Завершено ли восстановление
 		pub completed: bool,
 	}
 
+	/// Отложенное изменение периода ожидания
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PendingDelayUpdate<BlockNumber> {
+		/// Новый период ожидания (блоки)
+		pub delay_period: u32,
+		/// С какого блока изменение можно применить
+		pub apply_at: BlockNumber,
+	}
+
+	/// Резервный ключ восстановления (бумажная копия, аппаратный токен)
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct BackupKey<Balance> {
+		/// Публичный ключ, подписывающий запрос на восстановление
+		pub public_key: AuraPublicKey,
+		/// Период ожидания восстановления по резервному ключу (блоки)
+		pub delay_period: u32,
+		/// Депозит, заблокированный за регистрацию ключа
+		pub deposit: Balance,
+	}
+
+	/// Обязательства Фельдмана на коэффициенты многочлена разделения секрета
+	/// (см. модуль `vss`). Их количество равно порогу восстановления.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ShareCommitmentSet<BlockNumber> {
+		pub commitments: BoundedVec<ShareCommitment, ConstU32<MAX_TRUSTEES>>,
+		/// Когда шары были опубликованы
+		pub uploaded_at: BlockNumber,
+	}
+
+	/// Проверка шара доверенным контактом
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ShareStatus<BlockNumber> {
+		/// Шар выдан, контакт его еще не проверил
+		Pending,
+		/// Контакт доказал, что знает корректный шар, или владелец раскрыл
+		/// корректный шар в ответ на жалобу
+		Verified,
+		/// Контакт пожаловался на шар. Если владелец не раскроет корректный
+		/// шар до `respond_by`, шар считается некорректным.
+		Disputed { respond_by: BlockNumber },
+	}
+
+	/// Индекс шара контакта (точка `x` многочлена) и результат проверки
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ShareInfo<BlockNumber> {
+		pub index: u8,
+		pub status: ShareStatus<BlockNumber>,
+	}
+
+	/// Доверенный контакт и его статус (для runtime API)
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct TrusteeInfo<AccountId> {
+		/// Аккаунт доверенного контакта
+		pub account: AccountId,
+		/// Подтвердил ли контакт участие
+		pub confirmed: bool,
+		/// Поддержал ли контакт текущий запрос на восстановление
+		pub vouched: bool,
+	}
+
+	/// Состояние активного восстановления (для runtime API)
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct RecoveryStatus<AccountId, Balance, BlockNumber> {
+		/// Сам запрос
+		pub request: RecoveryRequest<AccountId, Balance, BlockNumber>,
+		/// Доверенные контакты, поддержавшие запрос
+		pub vouched: Vec<AccountId>,
+		/// Требуемое количество подтверждений
+		pub threshold: u8,
+		/// Можно ли выполнить восстановление в текущем блоке
+		pub executable: bool,
+	}
+
 	// ========== НОВЫЕ STORAGE ДЛЯ SOCIAL RECOVERY ==========
 
 	#[pallet::storage]
@@ -102,7 +770,7 @@ pub mod pallet {
 		_, 
 		Blake2_128Concat, 
 		T::AccountId, 
-		RecoveryConfig,
+		RecoveryConfig<BalanceOf<T>>,
 		OptionQuery
 	>;
 
@@ -115,10 +783,28 @@ pub mod pallet {
 		T::AccountId,           // Владелец
 		Blake2_128Concat, 
 		T::AccountId,           // Доверенный контакт
-		TrusteeShare,
+		TrusteeShare<T::AccountId>,
 		OptionQuery
 	>;
 
+	#[pallet::storage]
+	/// Обратный индекс `TrusteeShares`: владельцы, у которых аккаунт является
+	/// доверенным контактом. Нужен, чтобы при деактивации Aura ID убрать
+	/// контакт у всех владельцев.
+	pub type Trusteeships<T: Config> = StorageDoubleMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId,           // Доверенный контакт
+		Blake2_128Concat, 
+		T::AccountId,           // Владелец
+		(),
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	/// Позиция, с которой `on_idle` продолжит поиск истекших запросов на восстановление
+	pub type RecoverySweepCursor<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<256>>>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn get_active_recovery)]
 	/// Активные запросы на восстановление
@@ -126,43 +812,191 @@ pub mod pallet {
 		_, 
 		Blake2_128Concat, 
 		T::AccountId, 
-		RecoveryRequest,
+		RecoveryRequest<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
 		OptionQuery
 	>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_recovery_deposit)]
-	/// Заблокированные депозиты для восстановления
-	pub type RecoveryDeposits<T: Config> = StorageMap<
+	#[pallet::getter(fn get_recovery_vouch)]
+	/// Доверенные контакты, уже подтвердившие активный запрос (блок подтверждения)
+	pub type RecoveryVouches<T: Config> = StorageDoubleMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId,           // Потерянный аккаунт
+		Blake2_128Concat, 
+		T::AccountId,           // Доверенный контакт
+		BlockNumberFor<T>,
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_delay_update)]
+	/// Запланированные изменения периода ожидания
+	pub type PendingDelayUpdates<T: Config> = StorageMap<
 		_, 
 		Blake2_128Concat, 
 		T::AccountId, 
-		BalanceOf<T>, 
+		PendingDelayUpdate<BlockNumberFor<T>>,
 		OptionQuery
 	>;
 
-	// ========== СОБЫТИЯ ==========
+	#[pallet::storage]
+	#[pallet::getter(fn backup_key)]
+	/// Резервные ключи восстановления
+	pub type BackupKeys<T: Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		BackupKey<BalanceOf<T>>, 
+		OptionQuery
+	>;
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		// Существующие события
-		AuraIdCreated { account: T::AccountId, did: [u8; 32] },
-		
-		// Новые события для Social Recovery
-		RecoveryConfigured { 
-			account: T::AccountId, 
-			threshold: u8, 
-			total_trustees: u8 
-		},
-		TrusteeAdded { 
-			account: T::AccountId, 
-			trustee: T::AccountId 
-		},
-		TrusteeRemoved { 
-			account: T::AccountId, 
+	#[pallet::storage]
+	/// Активные запросы на восстановление, подписанные резервным ключом.
+	/// Такой запрос не требует подтверждений доверенных контактов.
+	pub type BackupApprovals<T: Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		(), 
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn share_commitments)]
+	/// Обязательства на шары секрета, опубликованные владельцем
+	pub type ShareCommitments<T: Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		ShareCommitmentSet<BlockNumberFor<T>>, 
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn share_state)]
+	/// Индексы и статус проверки шаров (владелец, контакт)
+	pub type ShareStates<T: Config> = StorageDoubleMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		ShareInfo<BlockNumberFor<T>>, 
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_policy)]
+	/// Политика восстановления. Если она задана, восстановление выполняется
+	/// по ней, а не по порогу из `RecoveryConfigs`.
+	pub type RecoveryPolicies<T: Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		RecoveryPolicy<T::AccountId>, 
+		OptionQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_recovery_deposit)]
+	/// Заблокированные депозиты для восстановления
+	pub type RecoveryDeposits<T: Config> = StorageMap<
+		_, 
+		Blake2_128Concat, 
+		T::AccountId, 
+		BalanceOf<T>, 
+		OptionQuery
+	>;
+
+	// ========== СОБЫТИЯ ==========
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		// Существующие события
+		AuraIdCreated { account: T::AccountId, did: [u8; 32] },
+		AuraIdDeactivated { account: T::AccountId, did: [u8; 32], forced: bool },
+		KeyRotated { 
+			account: T::AccountId, 
+			did: [u8; 32], 
+			old_key: AuraPublicKey, 
+			new_key: AuraPublicKey 
+		},
+		
+		// События DID документа
+		VerificationMethodAdded { account: T::AccountId, fragment: Fragment },
+		VerificationMethodRemoved { account: T::AccountId, fragment: Fragment },
+		ServiceAdded { account: T::AccountId, fragment: Fragment },
+		ServiceRemoved { account: T::AccountId, fragment: Fragment },
+		ControllerAdded { account: T::AccountId, controller: [u8; 32] },
+		ControllerRemoved { account: T::AccountId, controller: [u8; 32] },
+		
+		// Новые события для Social Recovery
+		RecoveryConfigured { 
+			account: T::AccountId, 
+			threshold: u8, 
+			total_trustees: u8 
+		},
+		TrusteeAdded { 
+			account: T::AccountId, 
+			trustee: T::AccountId 
+		},
+		TrusteeRemoved { 
+			account: T::AccountId, 
 			trustee: T::AccountId 
 		},
+		TrusteeConfirmed { 
+			account: T::AccountId, 
+			trustee: T::AccountId 
+		},
+		TrusteeDeclined { 
+			account: T::AccountId, 
+			trustee: T::AccountId 
+		},
+		RecoveryDelayUpdateScheduled { 
+			account: T::AccountId, 
+			delay_period: u32, 
+			apply_at: BlockNumberFor<T> 
+		},
+		RecoveryDelayUpdated { 
+			account: T::AccountId, 
+			delay_period: u32 
+		},
+		RecoveryRemoved { 
+			account: T::AccountId, 
+			deposit: BalanceOf<T> 
+		},
+		RecoveryPolicySet { account: T::AccountId },
+		RecoveryPolicyRemoved { account: T::AccountId },
+		BackupKeySet { 
+			account: T::AccountId, 
+			delay_period: u32 
+		},
+		BackupKeyRemoved { account: T::AccountId },
+		BackupRecoveryInitiated { 
+			lost_account: T::AccountId, 
+			requesting_account: T::AccountId 
+		},
+		SharesUploaded { 
+			account: T::AccountId, 
+			threshold: u8 
+		},
+		ShareVerified { 
+			account: T::AccountId, 
+			trustee: T::AccountId 
+		},
+		ShareDisputed { 
+			account: T::AccountId, 
+			trustee: T::AccountId, 
+			respond_by: BlockNumberFor<T> 
+		},
+		/// Владелец раскрыл корректный шар в ответ на жалобу
+		ShareRevealed { 
+			account: T::AccountId, 
+			trustee: T::AccountId, 
+			share: ShareScalar 
+		},
 		RecoveryInitiated { 
 			lost_account: T::AccountId, 
 			requesting_account: T::AccountId 
@@ -176,7 +1010,15 @@ pub mod pallet {
 			new_account: T::AccountId 
 		},
 		RecoveryCancelled { 
-			account: T::AccountId 
+			account: T::AccountId,
+			requesting_account: T::AccountId,
+			slashed: BalanceOf<T>,
+		},
+		/// Запрос на восстановление истек. Залог `released` возвращен инициатору.
+		RecoveryExpired {
+			lost_account: T::AccountId,
+			requesting_account: T::AccountId,
+			released: BalanceOf<T>,
 		},
 	}
 
@@ -187,6 +1029,21 @@ pub mod pallet {
 		// Существующие ошибки
 		AuraIdAlreadyExists,
 		AuraIdNotFound,
+		DidAlreadyRegistered,
+		KeyUnchanged,
+		DidDeactivated,
+		
+		// Ошибки DID документа
+		InvalidDocumentElement,
+		TooManyVerificationMethods,
+		TooManyServices,
+		TooManyControllers,
+		VerificationMethodAlreadyExists,
+		VerificationMethodNotFound,
+		ServiceAlreadyExists,
+		ServiceNotFound,
+		ControllerAlreadyExists,
+		ControllerNotFound,
 		
 		// Новые ошибки для Social Recovery
 		InvalidRecoveryThreshold,
@@ -200,6 +1057,173 @@ pub mod pallet {
 		DelayPeriodNotPassed,
 		InsufficientDeposit,
 		NotAuthorized,
+		AlreadyVouched,
+		RecoveryAlreadyCompleted,
+		InvalidKeyProof,
+		TrusteeAlreadyConfirmed,
+		TrusteeNotConfirmed,
+		InvalidRecoveryDelay,
+		NoPendingDelayUpdate,
+		RecoveryRequestExpired,
+		RecoveryNotExpired,
+		TooFewTrustees,
+		/// Политика некорректна или ее можно выполнить меньшим, чем
+		/// `MIN_THRESHOLD`, числом контактов
+		InvalidRecoveryPolicy,
+		/// Контакт используется в политике восстановления
+		TrusteeInPolicy,
+		/// Резервный ключ не зарегистрирован
+		BackupKeyNotFound,
+		/// Подпись резервного ключа неверна
+		InvalidBackupSignature,
+		/// Резервный ключ совместим только с политикой, корень которой - `Any`
+		BackupKeyConflictsWithPolicy,
+		/// Обязательства некорректны или их количество не равно порогу
+		InvalidShareCommitments,
+		/// Шары должны быть выданы каждому доверенному контакту ровно один раз
+		IncompleteShares,
+		/// Зашифрованный шар слишком большой
+		ShareTooLarge,
+		/// Контакту не выдан шар
+		ShareNotFound,
+		/// Шар уже проверен или оспорен
+		ShareNotPending,
+		/// На шар нет жалобы
+		ShareNotDisputed,
+		/// Доказательство знания шара неверно
+		InvalidShareProof,
+		/// Раскрытый шар не соответствует обязательствам
+		InvalidShare,
+		/// Смена ключа не подписана текущим ключом
+		InvalidRotationAuthorization,
+		/// Ключ версии 0 нельзя назначить - только заменить через `rotate_key`
+		LegacyKey,
+		/// Срок ответа на жалобу истек, шар уже считается некорректным
+		ShareComplaintExpired,
+		/// Аккаунт уже доверенный контакт у `MaxTrusteeships` аккаунтов
+		TooManyTrusteeships,
+		/// Контакт указан дважды или уже добавлен
+		DuplicateTrustee,
+		/// Аккаунт не может быть своим доверенным контактом
+		SelfTrustee,
+	}
+
+	// ========== GENESIS ==========
+
+	/// Начальные Aura ID и настройки восстановления (например, операторов и
+	/// системных аккаунтов). Доказательство владения ключом в генезисе не
+	/// требуется - за содержимое chain spec отвечает оператор сети.
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Aura ID: `(аккаунт, публичный ключ sr25519)`. Ключи других
+		/// алгоритмов подключаются после запуска сети через `rotate_key`.
+		pub identities: Vec<(T::AccountId, [u8; 32])>,
+		/// Восстановление: `(аккаунт, порог, доверенные контакты, период ожидания)`.
+		/// Доверенные контакты считаются подтвердившими свою роль.
+		pub recovery_setups: Vec<(T::AccountId, u8, Vec<T::AccountId>, Option<u32>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (account, public_key) in &self.identities {
+				assert!(
+					!AuraIdentities::<T>::contains_key(account),
+					"Duplicate Aura ID in genesis",
+				);
+
+				// Тот же nonce, что был бы подписан при обычной регистрации
+				let nonce = KeyProofNonces::<T>::get(account);
+				KeyProofNonces::<T>::insert(account, nonce.wrapping_add(1));
+
+				let public_key = AuraPublicKey::Sr25519(*public_key);
+				let did = Pallet::<T>::generate_did(account, &public_key, nonce);
+				assert!(!DidIndex::<T>::contains_key(did), "Duplicate DID in genesis");
+
+				AuraIdentities::<T>::insert(account, AuraIdRecord {
+					did,
+					public_key,
+					recovery_config: BoundedVec::default(),
+					created: Some(Zero::zero()),
+				});
+				DidIndex::<T>::insert(did, account);
+			}
+
+			for (account, threshold, trustees, delay_period) in &self.recovery_setups {
+				assert!(
+					AuraIdentities::<T>::contains_key(account),
+					"Genesis recovery setup for an account without Aura ID",
+				);
+				assert!(
+					!RecoveryConfigs::<T>::contains_key(account),
+					"Duplicate recovery setup in genesis",
+				);
+
+				let delay_period = delay_period.unwrap_or_else(T::DefaultRecoveryDelay::get);
+				assert!(
+					Pallet::<T>::ensure_valid_delay(delay_period).is_ok(),
+					"Genesis recovery delay out of bounds",
+				);
+				assert!(
+					*threshold >= MIN_THRESHOLD && *threshold <= MAX_THRESHOLD,
+					"Invalid genesis recovery threshold",
+				);
+				assert!(
+					trustees.len() >= *threshold as usize && trustees.len() <= T::MaxTrustees::get() as usize,
+					"Invalid number of genesis trustees",
+				);
+
+				for trustee in trustees {
+					assert!(trustee != account, "Account cannot be its own trustee");
+					assert!(
+						AuraIdentities::<T>::contains_key(trustee),
+						"Genesis trustee without Aura ID",
+					);
+					assert!(
+						!TrusteeShares::<T>::contains_key(account, trustee),
+						"Duplicate genesis trustee",
+					);
+					assert!(
+						Pallet::<T>::ensure_trusteeship_available(trustee).is_ok(),
+						"Too many genesis trusteeships",
+					);
+					TrusteeShares::<T>::insert(account, trustee, TrusteeShare {
+						trustee_account: trustee.clone(),
+						share: BoundedVec::default(),
+						confirmed: true,
+					});
+					Trusteeships::<T>::insert(trustee, account, ());
+				}
+
+				let deposit = T::RecoveryDeposit::get();
+				T::Currency::reserve(account, deposit)
+					.expect("Genesis recovery account must be endowed with the recovery deposit");
+
+				RecoveryConfigs::<T>::insert(account, RecoveryConfig {
+					threshold: *threshold,
+					total_trustees: trustees.len() as u8,
+					delay_period,
+					active: true,
+					deposit,
+				});
+				RecoveryDeposits::<T>::insert(account, deposit);
+			}
+		}
+	}
+
+	// ========== HOOKS ==========
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			// Многоблочная миграция переносит записи порциями в свободное место блока
+			if MigrationCursor::<T>::exists() {
+				return crate::migrations::migrate_step::<T>(remaining_weight);
+			}
+			// Истекшие запросы на восстановление удаляются в свободное место блока
+			Self::sweep_expired_recoveries(remaining_weight)
+		}
 	}
 
 	// ========== CALL ФУНКЦИИ ==========
@@ -207,18 +1231,25 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		// СУЩЕСТВУЮЩАЯ ФУНКЦИЯ - обновлена
+		// `proof` - подпись ключом `public_key` над `key_proof_payload(who, nonce)`
+		// тем же алгоритмом, что указан в `public_key`.
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::create_aura_id(recovery_config.len() as u32))]
 		pub fn create_aura_id(
 			origin: OriginFor<T>,
-			public_key: [u8; 32],
+			public_key: AuraPublicKey,
 			recovery_config: Vec<u8>,
+			proof: AuraSignature,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			ensure!(!AuraIdentities::<T>::contains_key(&who), Error::<T>::AuraIdAlreadyExists);
 
-			let did = Self::generate_did(&public_key);
+			// DID может получить только владелец ключа
+			let nonce = Self::verify_key_proof(&who, &public_key, &proof)?;
+
+			let did = Self::generate_did(&who, &public_key, nonce);
+			ensure!(!DidIndex::<T>::contains_key(did), Error::<T>::DidAlreadyRegistered);
 
 			let bounded_recovery_config: BoundedVec<u8, ConstU32<1024>> = recovery_config
 				.try_into()
@@ -228,7 +1259,7 @@ pub mod pallet {
 				did,
 				public_key,
 				recovery_config: bounded_recovery_config,
-				created: 0u32, // Will implement proper timestamp later
+				created: Some(frame_system::Pallet::<T>::block_number()),
 			};
 
 			AuraIdentities::<T>::insert(&who, record.clone());
@@ -241,16 +1272,25 @@ pub mod pallet {
 
 		// ОБНОВЛЕННАЯ ФУНКЦИЯ - теперь полноценная Social Recovery
 		#[pallet::call_index(1)]
-		#[pallet::weight(50_000)]
+		#[pallet::weight(T::WeightInfo::setup_recovery(trustees.len() as u32))]
 		pub fn setup_recovery(
 			origin: OriginFor<T>,
 			threshold: u8,
-			trustees: Vec<T::AccountId>,
+			trustees: Vec<TrusteeLookupOf<T>>,
+			delay_period: Option<u32>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			let trustees = trustees
+				.into_iter()
+				.map(T::TrusteeLookup::lookup)
+				.collect::<Result<Vec<_>, _>>()?;
 
 			ensure!(!RecoveryConfigs::<T>::contains_key(&who), Error::<T>::RecoveryAlreadyConfigured);
 			
+			// Период ожидания: свой для аккаунта или по умолчанию
+			let delay_period = delay_period.unwrap_or_else(T::DefaultRecoveryDelay::get);
+			Self::ensure_valid_delay(delay_period)?;
+			
 			// Проверяем порог
 			ensure!(
 				threshold >= MIN_THRESHOLD && threshold <= MAX_THRESHOLD,
@@ -263,9 +1303,12 @@ pub mod pallet {
 				Error::<T>::TooManyTrustees
 			);
 			
-			// Проверяем, что все доверенные контакты имеют Aura ID
-			for trustee in &trustees {
+			// Проверяем, что все доверенные контакты разные, не сам владелец и имеют Aura ID
+			for (index, trustee) in trustees.iter().enumerate() {
+				ensure!(*trustee != who, Error::<T>::SelfTrustee);
+				ensure!(!trustees[..index].contains(trustee), Error::<T>::DuplicateTrustee);
 				ensure!(AuraIdentities::<T>::contains_key(trustee), Error::<T>::AuraIdNotFound);
+				Self::ensure_trusteeship_available(trustee)?;
 			}
 			
 			// Блокируем депозит
@@ -276,7 +1319,7 @@ pub mod pallet {
 			let config = RecoveryConfig {
 				threshold,
 				total_trustees,
-				delay_period: DEFAULT_RECOVERY_DELAY,
+				delay_period,
 				active: true,
 				deposit,
 			};
@@ -292,6 +1335,7 @@ pub mod pallet {
 					confirmed: false,
 				};
 				TrusteeShares::<T>::insert(&who, &trustee, share);
+				Trusteeships::<T>::insert(&trustee, &who, ());
 				
 				Self::deposit_event(Event::TrusteeAdded { 
 					account: who.clone(), 
@@ -310,28 +1354,34 @@ pub mod pallet {
 
 		// НОВАЯ ФУНКЦИЯ - добавление доверенного контакта
 		#[pallet::call_index(2)]
-		#[pallet::weight(30_000)]
+		#[pallet::weight(T::WeightInfo::add_trustee())]
 		pub fn add_trustee(
 			origin: OriginFor<T>,
-			trustee: T::AccountId,
+			trustee: TrusteeLookupOf<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			let trustee = T::TrusteeLookup::lookup(trustee)?;
 			
 			let mut config = RecoveryConfigs::<T>::get(&who)
 				.ok_or(Error::<T>::RecoveryNotConfigured)?;
 			
+			// Пока идет восстановление, состав доверенных контактов заморожен
+			ensure!(!Self::recovery_in_progress(&who), Error::<T>::RecoveryAlreadyActive);
+			
 			// Проверяем лимит доверенных контактов
 			ensure!(
 				config.total_trustees < T::MaxTrustees::get() as u8,
 				Error::<T>::TooManyTrustees
 			);
 			
+			ensure!(trustee != who, Error::<T>::SelfTrustee);
 			ensure!(AuraIdentities::<T>::contains_key(&trustee), Error::<T>::AuraIdNotFound);
+			Self::ensure_trusteeship_available(&trustee)?;
 			
 			// Проверяем, что контакт еще не добавлен
 			ensure!(
 				!TrusteeShares::<T>::contains_key(&who, &trustee),
-				Error::<T>::TrusteeNotFound
+				Error::<T>::DuplicateTrustee
 			);
 			
 			// Добавляем доверенный контакт
@@ -342,6 +1392,7 @@ pub mod pallet {
 			};
 			
 			TrusteeShares::<T>::insert(&who, &trustee, share);
+			Trusteeships::<T>::insert(&trustee, &who, ());
 			config.total_trustees += 1;
 			RecoveryConfigs::<T>::insert(&who, config);
 			
@@ -355,31 +1406,41 @@ pub mod pallet {
 
 		// НОВАЯ ФУНКЦИЯ - удаление доверенного контакта
 		#[pallet::call_index(3)]
-		#[pallet::weight(30_000)]
+		#[pallet::weight(T::WeightInfo::remove_trustee())]
 		pub fn remove_trustee(
 			origin: OriginFor<T>,
-			trustee: T::AccountId,
+			trustee: TrusteeLookupOf<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			let trustee = T::TrusteeLookup::lookup(trustee)?;
 			
 			let mut config = RecoveryConfigs::<T>::get(&who)
 				.ok_or(Error::<T>::RecoveryNotConfigured)?;
 			
+			// Пока идет восстановление, состав доверенных контактов заморожен
+			ensure!(!Self::recovery_in_progress(&who), Error::<T>::RecoveryAlreadyActive);
+			
 			// Проверяем, что контакт существует
 			ensure!(
 				TrusteeShares::<T>::contains_key(&who, &trustee),
 				Error::<T>::TrusteeNotFound
 			);
 			
-			// Удаляем контакт
+			// Порог не снижается: контактов должно остаться не меньше порога
+			ensure!(config.total_trustees > config.threshold, Error::<T>::TooFewTrustees);
+			
+			// Политика не должна ссылаться на удаленный контакт
+			ensure!(
+				RecoveryPolicies::<T>::get(&who).map_or(true, |policy| policy.trustees().all(|t| *t != trustee)),
+				Error::<T>::TrusteeInPolicy
+			);
+			
+			// Удаляем контакт. Шары остальных контактов остаются корректными.
 			TrusteeShares::<T>::remove(&who, &trustee);
+			ShareStates::<T>::remove(&who, &trustee);
+			Trusteeships::<T>::remove(&trustee, &who);
 			config.total_trustees -= 1;
 			
-			// Если количество контактов стало меньше порога, обновляем порог
-			if config.threshold > config.total_trustees {
-				config.threshold = config.total_trustees;
-			}
-			
 			RecoveryConfigs::<T>::insert(&who, config);
 			
 			Self::deposit_event(Event::TrusteeRemoved { 
@@ -392,13 +1453,18 @@ pub mod pallet {
 
 		// НОВАЯ ФУНКЦИЯ - инициация восстановления
 		#[pallet::call_index(4)]
-		#[pallet::weight(40_000)]
+		#[pallet::weight(
+			T::WeightInfo::initiate_recovery()
+				.saturating_add(T::WeightInfo::remove_expired_recovery(T::MaxTrustees::get()))
+		)]
 		pub fn initiate_recovery(
 			origin: OriginFor<T>,
-			lost_account: T::AccountId,
-			new_public_key: [u8; 32],
+			lost_account: AccountIdLookupOf<T>,
+			new_public_key: AuraPublicKey,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			let lost_account = T::Lookup::lookup(lost_account)?;
+			ensure!(!new_public_key.is_legacy(), Error::<T>::LegacyKey);
 			
 			// Проверяем, что потерянный аккаунт существует
 			ensure!(AuraIdentities::<T>::contains_key(&lost_account), Error::<T>::AuraIdNotFound);
@@ -409,18 +1475,28 @@ pub mod pallet {
 			
 			ensure!(config.active, Error::<T>::RecoveryNotConfigured);
 			
-			// Проверяем, что восстановление еще не активно
-			ensure!(
-				!ActiveRecoveries::<T>::contains_key(&lost_account),
-				Error::<T>::RecoveryAlreadyActive
-			);
+			// Проверяем, что восстановление еще не активно. Завершенный или
+			// истекший запрос не мешает новому: он удаляется, а залог
+			// истекшего запроса возвращается.
+			if let Some(active) = ActiveRecoveries::<T>::get(&lost_account) {
+				ensure!(
+					active.completed || Self::is_recovery_expired(&active),
+					Error::<T>::RecoveryAlreadyActive
+				);
+				Self::expire_recovery(&lost_account, active);
+			}
+			
+			// Блокируем залог инициатора - он будет списан, если владелец отменит запрос
+			let bond = T::RecoveryBond::get();
+			T::Currency::reserve(&who, bond)?;
 			
 			// Создаем запрос на восстановление
 			let recovery_request = RecoveryRequest {
 				requesting_account: who.clone(),
+				bond,
 				new_public_key,
 				submitted_shares: 0,
-				execute_at: frame_system::Pallet::<T>::block_number() + config.delay_period,
+				execute_at: frame_system::Pallet::<T>::block_number() + config.delay_period.into(),
 				completed: false,
 			};
 			
@@ -433,251 +1509,3506 @@ pub mod pallet {
 			
 			Ok(())
 		}
-	}
-
-	// ========== ВСПОМОГАТЕЛЬНЫЕ ФУНКЦИИ ==========
 
-	impl<T: Config> Pallet<T> {
-		pub fn generate_did(public_key: &[u8; 32]) -> [u8; 32] {
-			sp_io::hashing::blake2_256(public_key)
+		// НОВАЯ ФУНКЦИЯ - подтверждение восстановления доверенным контактом
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::submit_recovery_share())]
+		pub fn submit_recovery_share(
+			origin: OriginFor<T>,
+			lost_account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lost_account = T::Lookup::lookup(lost_account)?;
+			
+			let mut request = ActiveRecoveries::<T>::get(&lost_account)
+				.ok_or(Error::<T>::RecoveryNotActive)?;
+			
+			ensure!(!request.completed, Error::<T>::RecoveryAlreadyCompleted);
+			ensure!(!Self::is_recovery_expired(&request), Error::<T>::RecoveryRequestExpired);
+			
+			// Подтверждать может только доверенный контакт владельца,
+			// согласившийся на эту роль
+			let share = TrusteeShares::<T>::get(&lost_account, &who)
+				.ok_or(Error::<T>::NotAuthorized)?;
+			ensure!(share.confirmed, Error::<T>::TrusteeNotConfirmed);
+			// Контакт с деактивированным Aura ID больше не может подтверждать
+			ensure!(AuraIdentities::<T>::contains_key(&who), Error::<T>::AuraIdNotFound);
+			
+			// Каждый контакт голосует только один раз
+			ensure!(
+				!RecoveryVouches::<T>::contains_key(&lost_account, &who),
+				Error::<T>::AlreadyVouched
+			);
+			
+			RecoveryVouches::<T>::insert(
+				&lost_account,
+				&who,
+				frame_system::Pallet::<T>::block_number(),
+			);
+			request.submitted_shares = request.submitted_shares.saturating_add(1);
+			ActiveRecoveries::<T>::insert(&lost_account, request);
+			
+			Self::deposit_event(Event::RecoveryShareProvided { 
+				lost_account, 
+				trustee: who 
+			});
+			
+			Ok(())
 		}
-	}
-
-	// ========== СУЩЕСТВУЮЩАЯ СТРУКТУРА ==========
 
-	#[derive(Clone, Encode, Decode, PartialEq, TypeInfo, MaxEncodedLen)]
-	pub struct AuraIdRecord {
-		pub did: [u8; 32],
-		pub public_key: [u8; 32],
-		pub recovery_config: BoundedVec<u8, ConstU32<1024>>,
-		pub created: u32,
-	}
-}
+		// НОВАЯ ФУНКЦИЯ - выполнение восстановления после периода ожидания
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::execute_recovery(T::MaxTrustees::get()))]
+		pub fn execute_recovery(
+			origin: OriginFor<T>,
+			lost_account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lost_account = T::Lookup::lookup(lost_account)?;
+			
+			let request = ActiveRecoveries::<T>::get(&lost_account)
+				.ok_or(Error::<T>::RecoveryNotActive)?;
+			
+			ensure!(!request.completed, Error::<T>::RecoveryAlreadyCompleted);
+			ensure!(!Self::is_recovery_expired(&request), Error::<T>::RecoveryRequestExpired);
+			
+			// Завершить восстановление может только его инициатор
+			ensure!(request.requesting_account == who, Error::<T>::NotAuthorized);
+			
+			// Проверяем, что запрос подписан резервным ключом или собрано
+			// достаточно подтверждений
+			ensure!(
+				Self::recovery_approved(&lost_account, &request),
+				Error::<T>::InsufficientShares
+			);
+			
+			// Проверяем, что период ожидания истек
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= request.execute_at,
+				Error::<T>::DelayPeriodNotPassed
+			);
+			
+			// Перепривязываем Aura ID к новому ключу. DID остается прежним,
+			// поэтому запись в DidIndex продолжает указывать на тот же аккаунт.
+			let (did, _) = Self::replace_key(&lost_account, request.new_public_key)?;
+			DidIndex::<T>::insert(did, &lost_account);
+			
+			// Запрос завершен - возвращаем залог и удаляем подтверждения. Сам
+			// запрос остается с отметкой `completed`, пока его не заменит новый
+			// запрос или не удалит очистка истекших запросов.
+			T::Currency::unreserve(&request.requesting_account, request.bond);
+			Self::clear_recovery_approvals(&lost_account);
+			let new_account = request.requesting_account.clone();
+			ActiveRecoveries::<T>::insert(&lost_account, RecoveryRequest { completed: true, ..request });
+			
+			Self::deposit_event(Event::RecoveryExecuted { 
+				lost_account, 
+				new_account 
+			});
+			
+			Ok(())
+		}
 
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use frame_support::{
-		assert_noop, assert_ok, 
-		traits::{ConstU32, Currency, ReservableCurrency},
-		BoundedVec
-	};
-	use sp_core::H256;
-	use sp_runtime::{
-		traits::{BlakeTwo256, IdentityLookup},
-		BuildStorage, DispatchError,
-	};
-	use frame_system::pallet_prelude::BlockNumberFor;
+		// НОВАЯ ФУНКЦИЯ - отмена восстановления владельцем (вето)
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_recovery(T::MaxTrustees::get()))]
+		pub fn cancel_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			// Отменить может только владелец, у которого остался ключ
+			let request = ActiveRecoveries::<T>::get(&who)
+				.ok_or(Error::<T>::RecoveryNotActive)?;
+			
+			ensure!(!request.completed, Error::<T>::RecoveryAlreadyCompleted);
+			
+			// Списываем часть залога в пользу казны или владельца, остаток возвращаем
+			let initiator = request.requesting_account;
+			let slashed = Self::slash_recovery_bond(&who, &initiator, request.bond);
+			
+			Self::clear_recovery_request(&who);
+			
+			Self::deposit_event(Event::RecoveryCancelled { 
+				account: who,
+				requesting_account: initiator,
+				slashed,
+			});
+			
+			Ok(())
+		}
 
-	type Block = frame_system::mocking::MockBlock<Test>;
+		// НОВАЯ ФУНКЦИЯ - доверенный контакт соглашается на роль
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::accept_trusteeship())]
+		pub fn accept_trusteeship(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			ensure!(AuraIdentities::<T>::contains_key(&who), Error::<T>::AuraIdNotFound);
+			
+			TrusteeShares::<T>::try_mutate(&account, &who, |maybe_share| {
+				let share = maybe_share.as_mut().ok_or(Error::<T>::TrusteeNotFound)?;
+				ensure!(!share.confirmed, Error::<T>::TrusteeAlreadyConfirmed);
+				share.confirmed = true;
+				Ok::<_, DispatchError>(())
+			})?;
+			
+			Self::deposit_event(Event::TrusteeConfirmed { 
+				account, 
+				trustee: who 
+			});
+			
+			Ok(())
+		}
 
-	frame_support::construct_runtime!(
-		pub enum Test {
-			System: frame_system,
-			AuraIdentity: pallet,
+		// НОВАЯ ФУНКЦИЯ - доверенный контакт отказывается от роли. Пока идет
+		// восстановление, отказ запрещен: иначе контакты могли бы сорвать
+		// запрос, уменьшив число контактов ниже порога. Отказ, после которого
+		// контактов станет меньше порога, тоже запрещен - владелец должен
+		// сначала добавить контакт. Как и при `remove_trustee`, контакт из
+		// политики восстановления не может отказаться, пока владелец не
+		// обновит политику: иначе ее лист никогда не выполнится.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::decline_trusteeship())]
+		pub fn decline_trusteeship(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			
+			ensure!(
+				TrusteeShares::<T>::contains_key(&account, &who),
+				Error::<T>::TrusteeNotFound
+			);
+			let mut config = RecoveryConfigs::<T>::get(&account)
+				.ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(!Self::recovery_in_progress(&account), Error::<T>::RecoveryAlreadyActive);
+			ensure!(config.total_trustees > config.threshold, Error::<T>::TooFewTrustees);
+			ensure!(
+				RecoveryPolicies::<T>::get(&account).map_or(true, |policy| policy.trustees().all(|t| *t != who)),
+				Error::<T>::TrusteeInPolicy
+			);
+			
+			TrusteeShares::<T>::remove(&account, &who);
+			ShareStates::<T>::remove(&account, &who);
+			Trusteeships::<T>::remove(&who, &account);
+			config.total_trustees -= 1;
+			RecoveryConfigs::<T>::insert(&account, config);
+			
+			// Голос контакта за завершенный или истекший запрос больше не учитывается
+			Self::withdraw_vouch(&account, &who);
+			
+			Self::deposit_event(Event::TrusteeDeclined { 
+				account, 
+				trustee: who 
+			});
+			
+			Ok(())
 		}
-	);
 
-	// Конфигурация для тестов
-	pub struct TestMaxTrustees;
-	impl Get<u32> for TestMaxTrustees {
-		fn get() -> u32 { 5 }
-	}
+		// НОВАЯ ФУНКЦИЯ - изменение периода ожидания восстановления.
+		// Увеличение применяется сразу, уменьшение - только после истечения
+		// текущего периода ожидания, чтобы укравший ключ не мог его сократить.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::update_recovery_config())]
+		pub fn update_recovery_config(
+			origin: OriginFor<T>,
+			delay_period: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			let mut config = RecoveryConfigs::<T>::get(&who)
+				.ok_or(Error::<T>::RecoveryNotConfigured)?;
+			
+			Self::ensure_valid_delay(delay_period)?;
+			
+			if delay_period >= config.delay_period {
+				config.delay_period = delay_period;
+				RecoveryConfigs::<T>::insert(&who, config);
+				PendingDelayUpdates::<T>::remove(&who);
+				
+				Self::deposit_event(Event::RecoveryDelayUpdated { 
+					account: who, 
+					delay_period 
+				});
+			} else {
+				let apply_at = frame_system::Pallet::<T>::block_number() + config.delay_period.into();
+				PendingDelayUpdates::<T>::insert(&who, PendingDelayUpdate { delay_period, apply_at });
+				
+				Self::deposit_event(Event::RecoveryDelayUpdateScheduled { 
+					account: who, 
+					delay_period, 
+					apply_at 
+				});
+			}
+			
+			Ok(())
+		}
 
-	pub struct TestRecoveryDeposit;
-	impl Get<u128> for TestRecoveryDeposit {
-		fn get() -> u128 { 100 }
+		// НОВАЯ ФУНКЦИЯ - применение запланированного изменения периода ожидания
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::apply_recovery_config_update())]
+		pub fn apply_recovery_config_update(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			let update = PendingDelayUpdates::<T>::get(&who)
+				.ok_or(Error::<T>::NoPendingDelayUpdate)?;
+			
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= update.apply_at,
+				Error::<T>::DelayPeriodNotPassed
+			);
+			
+			RecoveryConfigs::<T>::try_mutate(&who, |maybe_config| {
+				let config = maybe_config.as_mut().ok_or(Error::<T>::RecoveryNotConfigured)?;
+				config.delay_period = update.delay_period;
+				Ok::<_, DispatchError>(())
+			})?;
+			PendingDelayUpdates::<T>::remove(&who);
+			
+			Self::deposit_event(Event::RecoveryDelayUpdated { 
+				account: who, 
+				delay_period: update.delay_period 
+			});
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - полное удаление системы восстановления с возвратом депозита
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::remove_recovery(T::MaxTrustees::get()))]
+		pub fn remove_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			ensure!(RecoveryConfigs::<T>::contains_key(&who), Error::<T>::RecoveryNotConfigured);
+			
+			// Нельзя удалить конфигурацию, пока идет восстановление
+			ensure!(!Self::recovery_in_progress(&who), Error::<T>::RecoveryAlreadyActive);
+			
+			let deposit = Self::teardown_recovery(&who);
+			
+			Self::deposit_event(Event::RecoveryRemoved { 
+				account: who, 
+				deposit 
+			});
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - смена ключа Aura ID с сохранением DID.
+		// `authorization` - подпись текущим ключом над
+		// `key_rotation_payload(who, new_public_key, nonce)`, `proof` - подпись
+		// новым ключом над `key_proof_payload(who, nonce)` с тем же nonce.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::rotate_key())]
+		pub fn rotate_key(
+			origin: OriginFor<T>,
+			new_public_key: AuraPublicKey,
+			proof: AuraSignature,
+			authorization: AuraSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			let record = AuraIdentities::<T>::get(&who).ok_or(Error::<T>::AuraIdNotFound)?;
+			ensure!(record.public_key != new_public_key, Error::<T>::KeyUnchanged);
+			
+			// Текущий ключ должен разрешить смену: одного доступа к аккаунту
+			// недостаточно, чтобы перехватить DID
+			let nonce = KeyProofNonces::<T>::get(&who);
+			let payload = Self::key_rotation_payload(&who, &new_public_key, nonce);
+			ensure!(
+				record.public_key.verify_rotation(&payload[..], &authorization),
+				Error::<T>::InvalidRotationAuthorization
+			);
+			
+			// Новый ключ должен подтвердить владение
+			Self::verify_key_proof(&who, &new_public_key, &proof)?;
+			
+			let (did, old_key) = Self::replace_key(&who, new_public_key)?;
+			
+			Self::deposit_event(Event::KeyRotated { 
+				account: who, 
+				did, 
+				old_key, 
+				new_key: new_public_key 
+			});
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - добавление verification method в DID документ
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::add_verification_method())]
+		pub fn add_verification_method(
+			origin: OriginFor<T>,
+			fragment: Vec<u8>,
+			key_type: VerificationKeyType,
+			public_key: Vec<u8>,
+			controller: Option<DidLookupOf<T>>,
+			purposes: Vec<VerificationPurpose>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let controller = controller.map(T::DidLookup::lookup).transpose()?;
+			
+			ensure!(AuraIdentities::<T>::contains_key(&who), Error::<T>::AuraIdNotFound);
+			
+			let fragment = Self::bounded_element::<ConstU32<MAX_FRAGMENT_LENGTH>>(fragment)?;
+			let public_key = Self::bounded_element::<ConstU32<MAX_METHOD_KEY_LENGTH>>(public_key)?;
+			let mut purposes = purposes;
+			purposes.sort_by_key(|purpose| *purpose as u8);
+			purposes.dedup();
+			ensure!(!purposes.is_empty(), Error::<T>::InvalidDocumentElement);
+			let purposes: BoundedVec<VerificationPurpose, ConstU32<5>> = purposes
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidDocumentElement)?;
+			
+			if let Some(controller) = controller {
+				ensure!(DidIndex::<T>::contains_key(controller), Error::<T>::ControllerNotFound);
+				ensure!(!Tombstones::<T>::contains_key(controller), Error::<T>::DidDeactivated);
+			}
+			
+			ensure!(
+				!VerificationMethods::<T>::contains_key(&who, &fragment),
+				Error::<T>::VerificationMethodAlreadyExists
+			);
+			ensure!(
+				(VerificationMethods::<T>::iter_prefix(&who).count() as u32) < T::MaxVerificationMethods::get(),
+				Error::<T>::TooManyVerificationMethods
+			);
+			
+			let bytes = (&fragment, &key_type, &public_key, &controller, &purposes).encoded_size();
+			let deposit = Self::reserve_document_deposit(&who, bytes)?;
+			
+			VerificationMethods::<T>::insert(&who, &fragment, VerificationMethod {
+				key_type,
+				public_key,
+				controller,
+				purposes,
+				deposit,
+			});
+			Self::note_document_update(&who);
+			
+			Self::deposit_event(Event::VerificationMethodAdded { account: who, fragment });
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - удаление verification method из DID документа
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::remove_verification_method())]
+		pub fn remove_verification_method(
+			origin: OriginFor<T>,
+			fragment: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			let fragment = Self::bounded_element::<ConstU32<MAX_FRAGMENT_LENGTH>>(fragment)?;
+			let method = VerificationMethods::<T>::take(&who, &fragment)
+				.ok_or(Error::<T>::VerificationMethodNotFound)?;
+			
+			T::Currency::unreserve(&who, method.deposit);
+			Self::note_document_update(&who);
+			
+			Self::deposit_event(Event::VerificationMethodRemoved { account: who, fragment });
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - добавление service endpoint в DID документ
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::add_service())]
+		pub fn add_service(
+			origin: OriginFor<T>,
+			fragment: Vec<u8>,
+			service_type: Vec<u8>,
+			endpoint: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			ensure!(AuraIdentities::<T>::contains_key(&who), Error::<T>::AuraIdNotFound);
+			
+			let fragment = Self::bounded_element::<ConstU32<MAX_FRAGMENT_LENGTH>>(fragment)?;
+			let service_type = Self::bounded_element::<ConstU32<MAX_SERVICE_TYPE_LENGTH>>(service_type)?;
+			let endpoint = Self::bounded_element::<ConstU32<MAX_SERVICE_ENDPOINT_LENGTH>>(endpoint)?;
+			
+			ensure!(
+				!Services::<T>::contains_key(&who, &fragment),
+				Error::<T>::ServiceAlreadyExists
+			);
+			ensure!(
+				(Services::<T>::iter_prefix(&who).count() as u32) < T::MaxServices::get(),
+				Error::<T>::TooManyServices
+			);
+			
+			let bytes = (&fragment, &service_type, &endpoint).encoded_size();
+			let deposit = Self::reserve_document_deposit(&who, bytes)?;
+			
+			Services::<T>::insert(&who, &fragment, ServiceEndpoint {
+				service_type,
+				endpoint,
+				deposit,
+			});
+			Self::note_document_update(&who);
+			
+			Self::deposit_event(Event::ServiceAdded { account: who, fragment });
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - удаление service endpoint из DID документа
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::remove_service())]
+		pub fn remove_service(
+			origin: OriginFor<T>,
+			fragment: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			let fragment = Self::bounded_element::<ConstU32<MAX_FRAGMENT_LENGTH>>(fragment)?;
+			let service = Services::<T>::take(&who, &fragment)
+				.ok_or(Error::<T>::ServiceNotFound)?;
+			
+			T::Currency::unreserve(&who, service.deposit);
+			Self::note_document_update(&who);
+			
+			Self::deposit_event(Event::ServiceRemoved { account: who, fragment });
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - добавление контроллера DID документа
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::add_controller())]
+		pub fn add_controller(
+			origin: OriginFor<T>,
+			controller: DidLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let controller = T::DidLookup::lookup(controller)?;
+			
+			ensure!(AuraIdentities::<T>::contains_key(&who), Error::<T>::AuraIdNotFound);
+			ensure!(DidIndex::<T>::contains_key(controller), Error::<T>::ControllerNotFound);
+			ensure!(!Tombstones::<T>::contains_key(controller), Error::<T>::DidDeactivated);
+			ensure!(
+				!Controllers::<T>::contains_key(&who, controller),
+				Error::<T>::ControllerAlreadyExists
+			);
+			ensure!(
+				(Controllers::<T>::iter_prefix(&who).count() as u32) < T::MaxControllers::get(),
+				Error::<T>::TooManyControllers
+			);
+			
+			let deposit = Self::reserve_document_deposit(&who, controller.encoded_size())?;
+			Controllers::<T>::insert(&who, controller, deposit);
+			Self::note_document_update(&who);
+			
+			Self::deposit_event(Event::ControllerAdded { account: who, controller });
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - удаление контроллера DID документа
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::remove_controller())]
+		pub fn remove_controller(
+			origin: OriginFor<T>,
+			controller: DidLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let controller = T::DidLookup::lookup(controller)?;
+			
+			let deposit = Controllers::<T>::take(&who, controller)
+				.ok_or(Error::<T>::ControllerNotFound)?;
+			
+			T::Currency::unreserve(&who, deposit);
+			Self::note_document_update(&who);
+			
+			Self::deposit_event(Event::ControllerRemoved { account: who, controller });
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - деактивация Aura ID владельцем
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::deactivate_aura_id())]
+		pub fn deactivate_aura_id(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			Self::do_deactivate(&who, false)
+		}
+
+		// НОВАЯ ФУНКЦИЯ - принудительная деактивация Aura ID (root или governance)
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::deactivate_aura_id())]
+		pub fn force_deactivate_aura_id(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			
+			Self::do_deactivate(&account, true)
+		}
+
+		// НОВАЯ ФУНКЦИЯ - удаление истекшего запроса на восстановление.
+		// Вызвать может любой аккаунт. Залог возвращается инициатору; если
+		// запрос не собрал подтверждений, часть залога списывается.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::remove_expired_recovery(T::MaxTrustees::get()))]
+		pub fn remove_expired_recovery(
+			origin: OriginFor<T>,
+			lost_account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let lost_account = T::Lookup::lookup(lost_account)?;
+			
+			let request = ActiveRecoveries::<T>::get(&lost_account)
+				.ok_or(Error::<T>::RecoveryNotActive)?;
+			ensure!(Self::is_recovery_expired(&request), Error::<T>::RecoveryNotExpired);
+			
+			Self::expire_recovery(&lost_account, request);
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - политика восстановления (взвешенные контакты и
+		// дерево условий). `None` удаляет политику, после чего снова действует
+		// порог из конфигурации. Пока идет восстановление, политика заморожена.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_recovery_policy(
+			policy.as_ref().map_or(0, |nodes| nodes.len() as u32)
+		))]
+		pub fn set_recovery_policy(
+			origin: OriginFor<T>,
+			policy: Option<Vec<PolicyNode<TrusteeLookupOf<T>>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			ensure!(RecoveryConfigs::<T>::contains_key(&who), Error::<T>::RecoveryNotConfigured);
+			ensure!(!Self::recovery_in_progress(&who), Error::<T>::RecoveryAlreadyActive);
+			
+			let Some(nodes) = policy else {
+				RecoveryPolicies::<T>::remove(&who);
+				Self::deposit_event(Event::RecoveryPolicyRemoved { account: who });
+				return Ok(());
+			};
+			
+			let nodes = nodes
+				.into_iter()
+				.map(|node| {
+					Ok(match node {
+						PolicyNode::Trustee(trustee) => PolicyNode::Trustee(T::TrusteeLookup::lookup(trustee)?),
+						PolicyNode::All(children) => PolicyNode::All(children),
+						PolicyNode::Any(children) => PolicyNode::Any(children),
+						PolicyNode::AtLeast { threshold, children } => PolicyNode::AtLeast { threshold, children },
+					})
+				})
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+			let policy = RecoveryPolicy {
+				nodes: nodes.try_into().map_err(|_| Error::<T>::InvalidRecoveryPolicy)?,
+			};
+			
+			// Политику нельзя выполнить меньшим числом контактов, чем MIN_THRESHOLD
+			let min_approvals = policy.min_approvals().ok_or(Error::<T>::InvalidRecoveryPolicy)?;
+			ensure!(min_approvals >= MIN_THRESHOLD as u32, Error::<T>::InvalidRecoveryPolicy);
+			for trustee in policy.trustees() {
+				ensure!(TrusteeShares::<T>::contains_key(&who, trustee), Error::<T>::TrusteeNotFound);
+			}
+			ensure!(
+				policy.allows_alternatives() || !BackupKeys::<T>::contains_key(&who),
+				Error::<T>::BackupKeyConflictsWithPolicy
+			);
+			
+			RecoveryPolicies::<T>::insert(&who, policy);
+			Self::deposit_event(Event::RecoveryPolicySet { account: who });
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - регистрация резервного ключа восстановления.
+		// Ключ доказывает владение подписью `key_proof_payload`. Повторный
+		// вызов заменяет ключ; пока идет восстановление, ключ заморожен.
+		// Резервный ключ - отдельный путь в обход политики восстановления,
+		// поэтому политика должна допускать альтернативы (корень `Any`).
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_backup_key())]
+		pub fn set_backup_key(
+			origin: OriginFor<T>,
+			public_key: AuraPublicKey,
+			proof: AuraSignature,
+			delay_period: Option<u32>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			ensure!(AuraIdentities::<T>::contains_key(&who), Error::<T>::AuraIdNotFound);
+			ensure!(!Self::recovery_in_progress(&who), Error::<T>::RecoveryAlreadyActive);
+			
+			ensure!(
+				RecoveryPolicies::<T>::get(&who).map_or(true, |policy| policy.allows_alternatives()),
+				Error::<T>::BackupKeyConflictsWithPolicy
+			);
+			
+			let delay_period = delay_period.unwrap_or_else(T::DefaultRecoveryDelay::get);
+			Self::ensure_valid_delay(delay_period)?;
+			
+			Self::verify_key_proof(&who, &public_key, &proof)?;
+			
+			// Депозит берется один раз, замена ключа его не меняет
+			let deposit = match BackupKeys::<T>::get(&who) {
+				Some(backup) => backup.deposit,
+				None => {
+					let deposit = T::RecoveryDeposit::get();
+					T::Currency::reserve(&who, deposit)?;
+					deposit
+				},
+			};
+			
+			BackupKeys::<T>::insert(&who, BackupKey { public_key, delay_period, deposit });
+			
+			Self::deposit_event(Event::BackupKeySet { 
+				account: who, 
+				delay_period 
+			});
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - удаление резервного ключа и возврат депозита
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::remove_backup_key())]
+		pub fn remove_backup_key(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			ensure!(!Self::recovery_in_progress(&who), Error::<T>::RecoveryAlreadyActive);
+			
+			let backup = BackupKeys::<T>::take(&who).ok_or(Error::<T>::BackupKeyNotFound)?;
+			T::Currency::unreserve(&who, backup.deposit);
+			
+			Self::deposit_event(Event::BackupKeyRemoved { account: who });
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - восстановление резервным ключом. Запрос подписан
+		// резервным ключом (`backup_recovery_payload`) и не требует
+		// подтверждений доверенных контактов, но выполняется только после
+		// периода ожидания резервного ключа. Политика восстановления к нему
+		// не применяется: `set_backup_key` и `set_recovery_policy` допускают
+		// резервный ключ только вместе с политикой с корнем `Any`. Владелец
+		// может отменить запрос через `cancel_recovery`, как и обычный запрос. Действующий запрос
+		// без подтверждений вытесняется, как при отмене владельцем: часть
+		// залога его инициатора списывается.
+		#[pallet::call_index(26)]
+		#[pallet::weight(
+			T::WeightInfo::initiate_backup_recovery()
+				.saturating_add(T::WeightInfo::remove_expired_recovery(T::MaxTrustees::get()))
+		)]
+		pub fn initiate_backup_recovery(
+			origin: OriginFor<T>,
+			lost_account: AccountIdLookupOf<T>,
+			new_public_key: AuraPublicKey,
+			signature: AuraSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lost_account = T::Lookup::lookup(lost_account)?;
+			ensure!(!new_public_key.is_legacy(), Error::<T>::LegacyKey);
+			
+			ensure!(AuraIdentities::<T>::contains_key(&lost_account), Error::<T>::AuraIdNotFound);
+			let backup = BackupKeys::<T>::get(&lost_account).ok_or(Error::<T>::BackupKeyNotFound)?;
+			
+			// Подпись привязана к инициатору и nonce, поэтому ее нельзя
+			// перехватить или использовать повторно
+			let nonce = KeyProofNonces::<T>::get(&lost_account);
+			let payload = Self::backup_recovery_payload(&lost_account, &new_public_key, &who, nonce);
+			ensure!(
+				backup.public_key.verify(&payload[..], &signature),
+				Error::<T>::InvalidBackupSignature
+			);
+			
+			if let Some(active) = ActiveRecoveries::<T>::get(&lost_account) {
+				if active.completed || Self::is_recovery_expired(&active) {
+					Self::expire_recovery(&lost_account, active);
+				} else {
+					// Запрос без подтверждений мог создать кто угодно, чтобы
+					// занять восстановление; одобренный запрос не вытесняется
+					ensure!(
+						active.submitted_shares == 0 && !BackupApprovals::<T>::contains_key(&lost_account),
+						Error::<T>::RecoveryAlreadyActive
+					);
+					let initiator = active.requesting_account;
+					let slashed = Self::slash_recovery_bond(&lost_account, &initiator, active.bond);
+					Self::clear_recovery_request(&lost_account);
+					Self::deposit_event(Event::RecoveryCancelled {
+						account: lost_account.clone(),
+						requesting_account: initiator,
+						slashed,
+					});
+				}
+			}
+			
+			let bond = T::RecoveryBond::get();
+			T::Currency::reserve(&who, bond)?;
+			KeyProofNonces::<T>::insert(&lost_account, nonce.wrapping_add(1));
+			
+			ActiveRecoveries::<T>::insert(&lost_account, RecoveryRequest {
+				requesting_account: who.clone(),
+				bond,
+				new_public_key,
+				submitted_shares: 0,
+				execute_at: frame_system::Pallet::<T>::block_number() + backup.delay_period.into(),
+				completed: false,
+			});
+			BackupApprovals::<T>::insert(&lost_account, ());
+			
+			Self::deposit_event(Event::BackupRecoveryInitiated { 
+				lost_account, 
+				requesting_account: who 
+			});
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - публикация шаров секрета (Feldman VSS).
+		// Владелец публикует обязательства на коэффициенты многочлена (по
+		// одному на каждую единицу порога) и зашифрованный для каждого
+		// контакта шар. Индекс шара - позиция контакта в списке, начиная с 1.
+		// Повторная публикация заменяет все шары и сбрасывает их проверку.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::upload_shares(T::MaxTrustees::get()))]
+		pub fn upload_shares(
+			origin: OriginFor<T>,
+			commitments: Vec<ShareCommitment>,
+			shares: Vec<(TrusteeLookupOf<T>, Vec<u8>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			
+			let config = RecoveryConfigs::<T>::get(&who)
+				.ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(!Self::recovery_in_progress(&who), Error::<T>::RecoveryAlreadyActive);
+			
+			ensure!(
+				commitments.len() == config.threshold as usize && vss::valid_commitments(&commitments),
+				Error::<T>::InvalidShareCommitments
+			);
+			let commitments: BoundedVec<_, _> = commitments
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidShareCommitments)?;
+			ensure!(shares.len() == config.total_trustees as usize, Error::<T>::IncompleteShares);
+			
+			let _ = ShareStates::<T>::clear_prefix(&who, T::MaxTrustees::get(), None);
+			for (position, (trustee, share)) in shares.into_iter().enumerate() {
+				let trustee = T::TrusteeLookup::lookup(trustee)?;
+				let share: BoundedVec<u8, ConstU32<1024>> = share
+					.try_into()
+					.map_err(|_| Error::<T>::ShareTooLarge)?;
+				
+				// Контакт не может получить два шара
+				ensure!(!ShareStates::<T>::contains_key(&who, &trustee), Error::<T>::IncompleteShares);
+				TrusteeShares::<T>::try_mutate(&who, &trustee, |maybe_share| {
+					let trustee_share = maybe_share.as_mut().ok_or(Error::<T>::TrusteeNotFound)?;
+					trustee_share.share = share;
+					Ok::<_, DispatchError>(())
+				})?;
+				ShareStates::<T>::insert(&who, &trustee, ShareInfo {
+					index: position as u8 + 1,
+					status: ShareStatus::Pending,
+				});
+			}
+			
+			ShareCommitments::<T>::insert(&who, ShareCommitmentSet {
+				commitments,
+				uploaded_at: frame_system::Pallet::<T>::block_number(),
+			});
+			
+			Self::deposit_event(Event::SharesUploaded { 
+				account: who, 
+				threshold: config.threshold 
+			});
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - контакт расшифровал свой шар, проверил его по
+		// обязательствам и доказывает знание корректного шара, не раскрывая его
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::prove_share(T::MaxTrustees::get()))]
+		pub fn prove_share(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+			proof: ShareProof,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			
+			let mut info = ShareStates::<T>::get(&account, &who).ok_or(Error::<T>::ShareNotFound)?;
+			ensure!(info.status != ShareStatus::Verified, Error::<T>::ShareNotPending);
+			let set = ShareCommitments::<T>::get(&account).ok_or(Error::<T>::ShareNotFound)?;
+			
+			let context = Self::share_proof_context(&account, &who, info.index);
+			ensure!(
+				vss::verify_share_proof(&set.commitments, info.index, &context, &proof),
+				Error::<T>::InvalidShareProof
+			);
+			
+			info.status = ShareStatus::Verified;
+			ShareStates::<T>::insert(&account, &who, info);
+			
+			Self::deposit_event(Event::ShareVerified { 
+				account, 
+				trustee: who 
+			});
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - жалоба контакта на шар, не прошедший проверку.
+		// Владелец должен раскрыть шар через `reveal_share` в течение
+		// `ShareComplaintPeriod`, иначе шар считается некорректным.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::dispute_share())]
+		pub fn dispute_share(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			
+			let mut info = ShareStates::<T>::get(&account, &who).ok_or(Error::<T>::ShareNotFound)?;
+			ensure!(info.status == ShareStatus::Pending, Error::<T>::ShareNotPending);
+			
+			let respond_by = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::ShareComplaintPeriod::get().into());
+			info.status = ShareStatus::Disputed { respond_by };
+			ShareStates::<T>::insert(&account, &who, info);
+			
+			Self::deposit_event(Event::ShareDisputed { 
+				account, 
+				trustee: who, 
+				respond_by 
+			});
+			
+			Ok(())
+		}
+
+		// НОВАЯ ФУНКЦИЯ - ответ владельца на жалобу: шар раскрывается в
+		// открытом виде и проверяется по обязательствам. Раскрытие одного
+		// шара не ослабляет схему: для восстановления секрета по-прежнему
+		// нужен порог шаров. Ответить можно только до `respond_by`; после
+		// этого владелец должен заново разделить секрет (`upload_shares`).
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::reveal_share(T::MaxTrustees::get()))]
+		pub fn reveal_share(
+			origin: OriginFor<T>,
+			trustee: TrusteeLookupOf<T>,
+			share: ShareScalar,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let trustee = T::TrusteeLookup::lookup(trustee)?;
+			
+			let mut info = ShareStates::<T>::get(&who, &trustee).ok_or(Error::<T>::ShareNotFound)?;
+			let ShareStatus::Disputed { respond_by } = info.status else {
+				return Err(Error::<T>::ShareNotDisputed.into());
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= respond_by,
+				Error::<T>::ShareComplaintExpired
+			);
+			let set = ShareCommitments::<T>::get(&who).ok_or(Error::<T>::ShareNotFound)?;
+			ensure!(
+				vss::verify_share(&set.commitments, info.index, &share),
+				Error::<T>::InvalidShare
+			);
+			
+			info.status = ShareStatus::Verified;
+			ShareStates::<T>::insert(&who, &trustee, info);
+			
+			Self::deposit_event(Event::ShareRevealed { 
+				account: who, 
+				trustee, 
+				share 
+			});
+			
+			Ok(())
+		}
+	}
+
+	// ========== ВСПОМОГАТЕЛЬНЫЕ ФУНКЦИИ ==========
+
+	impl<T: Config> Pallet<T> {
+		/// Идет ли многоблочная миграция хранилища. Пока она не завершена,
+		/// runtime должен отклонять вызовы паллета (см. `BaseCallFilter`).
+		pub fn migration_ongoing() -> bool {
+			MigrationCursor::<T>::exists()
+		}
+
+		/// Деактивирует Aura ID: DID отмечается в `Tombstones`, восстановление и
+		/// DID документ удаляются, все депозиты и залог инициатора активного
+		/// восстановления возвращаются. Аккаунт перестает быть доверенным
+		/// контактом других владельцев.
+		pub(crate) fn do_deactivate(account: &T::AccountId, forced: bool) -> DispatchResult {
+			let record = AuraIdentities::<T>::take(account).ok_or(Error::<T>::AuraIdNotFound)?;
+			
+			// Активное восстановление теряет смысл - залог возвращается полностью
+			if let Some(request) = ActiveRecoveries::<T>::get(account) {
+				if !request.completed {
+					T::Currency::unreserve(&request.requesting_account, request.bond);
+				}
+				Self::clear_recovery_request(account);
+			}
+			Self::teardown_recovery(account);
+			Self::remove_trusteeships(account);
+			if let Some(backup) = BackupKeys::<T>::take(account) {
+				T::Currency::unreserve(account, backup.deposit);
+			}
+			
+			for (_, method) in VerificationMethods::<T>::drain_prefix(account) {
+				T::Currency::unreserve(account, method.deposit);
+			}
+			for (_, service) in Services::<T>::drain_prefix(account) {
+				T::Currency::unreserve(account, service.deposit);
+			}
+			for (_, deposit) in Controllers::<T>::drain_prefix(account) {
+				T::Currency::unreserve(account, deposit);
+			}
+			KeyHistory::<T>::remove(account);
+			
+			// Деактивация - последняя версия документа. История версий переходит
+			// в запись о деактивации: новый Aura ID аккаунта начнет ее заново.
+			Self::note_document_update(account);
+			let version = DocumentVersions::<T>::take(account);
+			
+			// Запись в DidIndex остается, чтобы DID нельзя было занять снова
+			Tombstones::<T>::insert(record.did, Tombstone {
+				account: account.clone(),
+				deactivated_at: version.updated,
+				forced,
+				version,
+			});
+			
+			Self::deposit_event(Event::AuraIdDeactivated { 
+				account: account.clone(), 
+				did: record.did, 
+				forced 
+			});
+			
+			Ok(())
+		}
+
+		/// Деактивирован ли DID
+		pub fn is_deactivated(did: &[u8; 32]) -> bool {
+			Tombstones::<T>::contains_key(did)
+		}
+
+		/// Аккаунт, которому принадлежит действующий DID. Для деактивированного
+		/// DID - `None`: запись в `DidIndex` остается только для защиты от
+		/// повторной регистрации.
+		pub fn account_by_did(did: &[u8; 32]) -> Option<T::AccountId> {
+			if Self::is_deactivated(did) {
+				return None;
+			}
+			DidIndex::<T>::get(did)
+		}
+
+		/// Удаляет активный запрос на восстановление и все подтверждения по нему
+		pub(crate) fn clear_recovery_request(lost_account: &T::AccountId) {
+			ActiveRecoveries::<T>::remove(lost_account);
+			Self::clear_recovery_approvals(lost_account);
+		}
+
+		/// Удаляет подтверждения запроса на восстановление
+		pub(crate) fn clear_recovery_approvals(lost_account: &T::AccountId) {
+			BackupApprovals::<T>::remove(lost_account);
+			let _ = RecoveryVouches::<T>::clear_prefix(lost_account, MAX_TRUSTEES, None);
+		}
+
+		/// Отзывает подтверждение контакта `trustee` по запросу аккаунта
+		pub(crate) fn withdraw_vouch(account: &T::AccountId, trustee: &T::AccountId) {
+			if RecoveryVouches::<T>::take(account, trustee).is_some() {
+				ActiveRecoveries::<T>::mutate(account, |maybe_request| {
+					if let Some(request) = maybe_request {
+						request.submitted_shares = request.submitted_shares.saturating_sub(1);
+					}
+				});
+			}
+		}
+
+		/// Может ли аккаунт стать доверенным контактом еще одного владельца
+		pub(crate) fn ensure_trusteeship_available(trustee: &T::AccountId) -> DispatchResult {
+			ensure!(
+				Trusteeships::<T>::iter_key_prefix(trustee).count() < T::MaxTrusteeships::get() as usize,
+				Error::<T>::TooManyTrusteeships
+			);
+			Ok(())
+		}
+
+		/// Убирает деактивированный аккаунт из доверенных контактов всех
+		/// владельцев. Его подтверждение активного запроса отзывается, а
+		/// политика, ссылающаяся на него, удаляется: ее лист больше нельзя
+		/// выполнить, и одобрение возвращается к порогу. Порог не снижается -
+		/// шары секрета по-прежнему восстанавливаются только `threshold`
+		/// контактами, поэтому владелец должен добавить новый контакт.
+		pub(crate) fn remove_trusteeships(trustee: &T::AccountId) {
+			for (owner, _) in Trusteeships::<T>::drain_prefix(trustee) {
+				TrusteeShares::<T>::remove(&owner, trustee);
+				ShareStates::<T>::remove(&owner, trustee);
+				RecoveryConfigs::<T>::mutate(&owner, |maybe_config| {
+					if let Some(config) = maybe_config {
+						config.total_trustees = config.total_trustees.saturating_sub(1);
+					}
+				});
+				Self::withdraw_vouch(&owner, trustee);
+				if RecoveryPolicies::<T>::get(&owner).map_or(false, |policy| policy.trustees().any(|t| t == trustee)) {
+					RecoveryPolicies::<T>::remove(&owner);
+					Self::deposit_event(Event::RecoveryPolicyRemoved { account: owner.clone() });
+				}
+				Self::deposit_event(Event::TrusteeRemoved { account: owner, trustee: trustee.clone() });
+			}
+		}
+
+		/// Блок, начиная с которого запрос на восстановление истек:
+		/// `RecoveryLifetime` блоков после окончания периода ожидания
+		pub fn recovery_expires_at(
+			request: &RecoveryRequest<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		) -> BlockNumberFor<T> {
+			request.execute_at.saturating_add(T::RecoveryLifetime::get().into())
+		}
+
+		/// Одобрен ли запрос на восстановление: подписан резервным ключом
+		/// либо собрано достаточно подтверждений - по политике, если она
+		/// задана, иначе по порогу конфигурации
+		pub(crate) fn recovery_approved(
+			account: &T::AccountId,
+			request: &RecoveryRequest<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		) -> bool {
+			if BackupApprovals::<T>::contains_key(account) {
+				return true;
+			}
+			match RecoveryPolicies::<T>::get(account) {
+				Some(policy) => policy.is_satisfied(|trustee| RecoveryVouches::<T>::contains_key(account, trustee)),
+				None => RecoveryConfigs::<T>::get(account)
+					.map_or(false, |config| config.threshold > 0 && request.submitted_shares >= config.threshold),
+			}
+		}
+
+		/// Есть ли у аккаунта действующий (не завершенный и не истекший) запрос
+		/// на восстановление.
+		/// Пока он есть, конфигурацию восстановления менять нельзя.
+		pub fn recovery_in_progress(account: &T::AccountId) -> bool {
+			ActiveRecoveries::<T>::get(account)
+				.map_or(false, |request| !request.completed && !Self::is_recovery_expired(&request))
+		}
+
+		pub(crate) fn is_recovery_expired(
+			request: &RecoveryRequest<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		) -> bool {
+			frame_system::Pallet::<T>::block_number() >= Self::recovery_expires_at(request)
+		}
+
+		/// Удаляет истекший или завершенный запрос. Залог истекшего
+		/// незавершенного запроса полностью возвращается инициатору.
+		pub(crate) fn expire_recovery(
+			lost_account: &T::AccountId,
+			request: RecoveryRequest<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		) {
+			Self::clear_recovery_request(lost_account);
+			// Залог завершенного запроса уже возвращен
+			if request.completed {
+				return;
+			}
+			T::Currency::unreserve(&request.requesting_account, request.bond);
+			
+			Self::deposit_event(Event::RecoveryExpired {
+				lost_account: lost_account.clone(),
+				requesting_account: request.requesting_account,
+				released: request.bond,
+			});
+		}
+
+		/// Списывает долю `RecoveryBondSlash` залога `bond` инициатора в пользу
+		/// `SlashBeneficiary` (или владельца `account`), остаток возвращает.
+		/// Возвращает списанную сумму.
+		pub(crate) fn slash_recovery_bond(
+			account: &T::AccountId,
+			initiator: &T::AccountId,
+			bond: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			let to_slash = T::RecoveryBondSlash::get() * bond;
+			let beneficiary = T::SlashBeneficiary::get().unwrap_or_else(|| account.clone());
+			let slashed = if to_slash.is_zero() {
+				Zero::zero()
+			} else {
+				// Если перевод невозможен, залог возвращается полностью
+				T::Currency::repatriate_reserved(initiator, &beneficiary, to_slash, BalanceStatus::Free)
+					.map_or(Zero::zero(), |remaining| to_slash.saturating_sub(remaining))
+			};
+			T::Currency::unreserve(initiator, bond.saturating_sub(slashed));
+			slashed
+		}
+
+		/// Проверяет порцию активных запросов на восстановление начиная с
+		/// `RecoverySweepCursor` и удаляет истекшие. Порция ограничена
+		/// `remaining_weight` и `MAX_RECOVERY_SWEEP`; дойдя до конца, поиск
+		/// начинается заново. Возвращает использованный вес.
+		pub(crate) fn sweep_expired_recoveries(remaining_weight: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			// Курсор
+			let base = db.reads_writes(1, 1);
+			let check = db.reads(1);
+			let expire = T::WeightInfo::remove_expired_recovery(T::MaxTrustees::get());
+			let per_request = check.saturating_add(expire);
+			
+			let Some(available) = remaining_weight.checked_sub(&base) else {
+				return Weight::zero();
+			};
+			let limit = (available.ref_time() / per_request.ref_time().max(1))
+				.min(MAX_RECOVERY_SWEEP as u64) as usize;
+			if limit == 0 {
+				return Weight::zero();
+			}
+			
+			let cursor = RecoverySweepCursor::<T>::get().unwrap_or_default();
+			let requests: Vec<_> = if cursor.is_empty() {
+				ActiveRecoveries::<T>::iter().take(limit).collect()
+			} else {
+				ActiveRecoveries::<T>::iter_from(cursor.into_inner()).take(limit).collect()
+			};
+			
+			let mut used = base.saturating_add(check.saturating_mul(requests.len() as u64));
+			let last = requests.last().map(|(account, _)| ActiveRecoveries::<T>::hashed_key_for(account));
+			let full = requests.len() == limit;
+			for (lost_account, request) in requests {
+				if Self::is_recovery_expired(&request) {
+					Self::expire_recovery(&lost_account, request);
+					used = used.saturating_add(expire);
+				}
+			}
+			
+			match last {
+				Some(key) if full => {
+					let cursor: BoundedVec<u8, ConstU32<256>> =
+						key.try_into().expect("ActiveRecoveries keys are far shorter than 256 bytes; qed");
+					RecoverySweepCursor::<T>::put(cursor);
+				},
+				_ => RecoverySweepCursor::<T>::kill(),
+			}
+			
+			used
+		}
+
+		/// Удаляет конфигурацию восстановления, всех доверенных контактов и
+		/// возвращает депозит. Возвращает размер разблокированного депозита.
+		pub(crate) fn teardown_recovery(account: &T::AccountId) -> BalanceOf<T> {
+			RecoveryConfigs::<T>::remove(account);
+			RecoveryPolicies::<T>::remove(account);
+			PendingDelayUpdates::<T>::remove(account);
+			ShareCommitments::<T>::remove(account);
+			for (trustee, _) in TrusteeShares::<T>::drain_prefix(account) {
+				Trusteeships::<T>::remove(&trustee, account);
+			}
+			let _ = ShareStates::<T>::clear_prefix(account, T::MaxTrustees::get(), None);
+			
+			let deposit = RecoveryDeposits::<T>::take(account).unwrap_or_else(Zero::zero);
+			T::Currency::unreserve(account, deposit);
+			deposit
+		}
+
+		/// Данные, которые подписываются ключом в доказательстве владения:
+		/// SCALE-кодирование `(KEY_PROOF_CONTEXT, genesis_hash, account, nonce)`
+		pub fn key_proof_payload(account: &T::AccountId, nonce: u32) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			(KEY_PROOF_CONTEXT, genesis_hash, account, nonce).encode()
+		}
+
+		/// Данные, которые подписываются текущим ключом для смены ключа:
+		/// SCALE-кодирование `(KEY_ROTATION_CONTEXT, genesis_hash, account,
+		/// new_public_key, nonce)`, где `nonce` - текущее значение `KeyProofNonces`
+		pub fn key_rotation_payload(account: &T::AccountId, new_public_key: &AuraPublicKey, nonce: u32) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			(KEY_ROTATION_CONTEXT, genesis_hash, account, new_public_key, nonce).encode()
+		}
+
+		/// Данные, которые подписываются резервным ключом для восстановления:
+		/// SCALE-кодирование `(BACKUP_RECOVERY_CONTEXT, genesis_hash, lost_account,
+		/// new_public_key, requesting_account, nonce)`, где `nonce` - текущее
+		/// значение `KeyProofNonces` потерянного аккаунта
+		pub fn backup_recovery_payload(
+			lost_account: &T::AccountId,
+			new_public_key: &AuraPublicKey,
+			requesting_account: &T::AccountId,
+			nonce: u32,
+		) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			(BACKUP_RECOVERY_CONTEXT, genesis_hash, lost_account, new_public_key, requesting_account, nonce).encode()
+		}
+
+		/// Контекст доказательства знания шара: SCALE-кодирование
+		/// `(SHARE_PROOF_CONTEXT, genesis_hash, account, trustee, index)`
+		pub fn share_proof_context(account: &T::AccountId, trustee: &T::AccountId, index: u8) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			(SHARE_PROOF_CONTEXT, genesis_hash, account, trustee, index).encode()
+		}
+
+		/// Контакты, на жалобы которых владелец не ответил вовремя: их шары
+		/// считаются некорректными, и владелец должен заново разделить секрет
+		pub fn invalid_shares(account: &T::AccountId) -> Vec<T::AccountId> {
+			let now = frame_system::Pallet::<T>::block_number();
+			ShareStates::<T>::iter_prefix(account)
+				.filter(|(_, info)| matches!(info.status, ShareStatus::Disputed { respond_by } if now > respond_by))
+				.map(|(trustee, _)| trustee)
+				.collect()
+		}
+
+		/// Шары аккаунта: индекс и статус проверки для каждого контакта
+		pub fn share_states(account: &T::AccountId) -> Vec<(T::AccountId, ShareInfo<BlockNumberFor<T>>)> {
+			ShareStates::<T>::iter_prefix(account).collect()
+		}
+
+		/// Проверяет подпись ключом `public_key` и увеличивает nonce аккаунта.
+		/// Возвращает использованный nonce.
+		pub(crate) fn verify_key_proof(
+			account: &T::AccountId,
+			public_key: &AuraPublicKey,
+			proof: &AuraSignature,
+		) -> Result<u32, DispatchError> {
+			ensure!(!public_key.is_legacy(), Error::<T>::LegacyKey);
+			let nonce = KeyProofNonces::<T>::get(account);
+			let payload = Self::key_proof_payload(account, nonce);
+			ensure!(
+				public_key.verify(&payload[..], proof),
+				Error::<T>::InvalidKeyProof
+			);
+			KeyProofNonces::<T>::insert(account, nonce.wrapping_add(1));
+			Ok(nonce)
+		}
+
+		/// Заменяет ключ Aura ID и переносит старый ключ в историю.
+		/// Возвращает DID и старый ключ.
+		pub(crate) fn replace_key(
+			account: &T::AccountId,
+			new_public_key: AuraPublicKey,
+		) -> Result<([u8; 32], AuraPublicKey), DispatchError> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut history = KeyHistory::<T>::get(account);
+			
+			let (did, old_key) = AuraIdentities::<T>::try_mutate(account, |maybe_record| {
+				let record = maybe_record.as_mut().ok_or(Error::<T>::AuraIdNotFound)?;
+				let old_key = sp_std::mem::replace(&mut record.public_key, new_public_key);
+				
+				// Текущий ключ действует с момента прошлой смены или с регистрации.
+				// Если блок регистрации неизвестен, ключ считается действующим с генезиса.
+				let valid_from = history
+					.last()
+					.map(|key| key.valid_until)
+					.or(record.created)
+					.unwrap_or_else(Zero::zero);
+				
+				// Если история заполнена, забываем самый старый ключ
+				if history.is_full() {
+					history.remove(0);
+				}
+				let _ = history.try_push(KeyRecord { public_key: old_key, valid_from, valid_until: now });
+				
+				Ok::<_, DispatchError>((record.did, old_key))
+			})?;
+			
+			KeyHistory::<T>::insert(account, history);
+			Self::note_document_update(account);
+			Ok((did, old_key))
+		}
+
+		/// Отмечает изменение DID документа: новая версия и блок изменения
+		pub(crate) fn note_document_update(account: &T::AccountId) {
+			DocumentVersions::<T>::mutate(account, |version| {
+				version.version_id = version.version_id.saturating_add(1);
+				version.updated = frame_system::Pallet::<T>::block_number();
+			});
+		}
+
+		/// Депозит за элемент DID документа размером `bytes` байт
+		pub fn document_deposit(bytes: usize) -> BalanceOf<T> {
+			let bytes: BalanceOf<T> = (bytes as u32).into();
+			T::DocumentDepositBase::get().saturating_add(T::DocumentByteDeposit::get().saturating_mul(bytes))
+		}
+
+		/// Блокирует депозит за элемент DID документа
+		fn reserve_document_deposit(
+			account: &T::AccountId,
+			bytes: usize,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let deposit = Self::document_deposit(bytes);
+			T::Currency::reserve(account, deposit)?;
+			Ok(deposit)
+		}
+
+		/// Преобразует непустые входные данные в элемент DID документа ограниченной длины
+		fn bounded_element<S: Get<u32>>(data: Vec<u8>) -> Result<BoundedVec<u8, S>, DispatchError> {
+			ensure!(!data.is_empty(), Error::<T>::InvalidDocumentElement);
+			data.try_into().map_err(|_| Error::<T>::InvalidDocumentElement.into())
+		}
+
+		/// Ключ, который действовал для Aura ID в блоке `at`. `None` для блоков
+		/// до регистрации Aura ID.
+		pub fn key_at(account: &T::AccountId, at: BlockNumberFor<T>) -> Option<AuraPublicKey> {
+			let record = AuraIdentities::<T>::get(account)?;
+			if record.created.map_or(false, |created| at < created) {
+				return None;
+			}
+			let history = KeyHistory::<T>::get(account);
+			
+			match history.iter().find(|key| key.valid_from <= at && at < key.valid_until) {
+				Some(key) => Some(key.public_key),
+				None if history.last().map_or(true, |key| at >= key.valid_until) => Some(record.public_key),
+				// Ключ старше сохраненной истории
+				None => None,
+			}
+		}
+
+		/// Проверяет подпись ключом, который действовал для Aura ID в блоке `at`
+		pub fn verify_signature_at(
+			account: &T::AccountId,
+			message: &[u8],
+			signature: &AuraSignature,
+			at: BlockNumberFor<T>,
+		) -> bool {
+			Self::key_at(account, at).map_or(false, |key| key.verify(message, signature))
+		}
+
+		/// Проверяет, что период ожидания в допустимых границах
+		fn ensure_valid_delay(delay_period: u32) -> DispatchResult {
+			ensure!(
+				delay_period >= T::MinRecoveryDelay::get() && delay_period <= T::MaxRecoveryDelay::get(),
+				Error::<T>::InvalidRecoveryDelay
+			);
+			Ok(())
+		}
+
+		/// Доверенные контакты аккаунта и их статус
+		pub fn trustees(account: &T::AccountId) -> Vec<TrusteeInfo<T::AccountId>> {
+			TrusteeShares::<T>::iter_prefix(account)
+				.map(|(trustee, share)| TrusteeInfo {
+					vouched: RecoveryVouches::<T>::contains_key(account, &trustee),
+					account: trustee,
+					confirmed: share.confirmed,
+				})
+				.collect()
+		}
+
+		/// Состояние активного восстановления аккаунта
+		pub fn recovery_status(
+			account: &T::AccountId,
+		) -> Option<RecoveryStatus<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+			let request = ActiveRecoveries::<T>::get(account)?;
+			let threshold = RecoveryConfigs::<T>::get(account).map_or(0, |config| config.threshold);
+			let executable = !request.completed
+				&& Self::recovery_approved(account, &request)
+				&& frame_system::Pallet::<T>::block_number() >= request.execute_at
+				&& !Self::is_recovery_expired(&request);
+			
+			Some(RecoveryStatus {
+				vouched: RecoveryVouches::<T>::iter_key_prefix(account).collect(),
+				request,
+				threshold,
+				executable,
+			})
+		}
+
+		/// Содержимое DID документа. `None`, если DID неизвестен, деактивирован
+		/// или аккаунт с тех пор зарегистрировал другой Aura ID.
+		pub fn did_document(did: &[u8; 32]) -> Option<DidDocument<BalanceOf<T>, BlockNumberFor<T>>> {
+			let account = Self::account_by_did(did)?;
+			let record = AuraIdentities::<T>::get(&account).filter(|record| &record.did == did)?;
+			
+			Some(DidDocument {
+				record,
+				methods: VerificationMethods::<T>::iter_prefix(&account).collect(),
+				services: Services::<T>::iter_prefix(&account).collect(),
+				controllers: Controllers::<T>::iter_key_prefix(&account).collect(),
+				version: DocumentVersions::<T>::get(&account),
+			})
+		}
+
+		/// Доверенные контакты, которые еще не подтвердили свое участие
+		pub fn unconfirmed_trustees(account: &T::AccountId) -> Vec<T::AccountId> {
+			TrusteeShares::<T>::iter_prefix(account)
+				.filter(|(_, share)| !share.confirmed)
+				.map(|(trustee, _)| trustee)
+				.collect()
+		}
+
+		/// Получение DID (версия 2):
+		///
+		/// `did = blake2_256(SCALE(DID_DERIVATION_CONTEXT, account, public_key, nonce))`
+		///
+		/// где `DID_DERIVATION_CONTEXT = b"did:aura:v2"` кодируется как `Vec<u8>`
+		/// (compact-длина + байты), `account` - SCALE-кодирование `AccountId`
+		/// (32 байта в runtime), `public_key` - SCALE-кодирование `AuraPublicKey`:
+		/// байт алгоритма (0 - sr25519, 1 - ed25519, 2 - ecdsa secp256k1, 3 - P-256)
+		/// и ключ (32 или 33 байта), `nonce` - `u32` little-endian, значение
+		/// `KeyProofNonces` в момент регистрации (тот же nonce, что подписан в
+		/// доказательстве владения ключом). Строковая форма - `did:aura:<hex(did)>`.
+		///
+		/// DID, полученные по версии 1 (ключ 32 байта без алгоритма), не меняются.
+		pub fn generate_did(account: &T::AccountId, public_key: &AuraPublicKey, nonce: u32) -> [u8; 32] {
+			(DID_DERIVATION_CONTEXT, account, public_key, nonce)
+				.using_encoded(sp_io::hashing::blake2_256)
+		}
+	}
+
+	// ========== СУЩЕСТВУЮЩАЯ СТРУКТУРА ==========
+
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct AuraIdRecord<BlockNumber> {
+		pub did: [u8; 32],
+		/// Ключ Aura ID с алгоритмом (с версии хранилища 2)
+		pub public_key: AuraPublicKey,
+		pub recovery_config: BoundedVec<u8, ConstU32<1024>>,
+		/// Блок регистрации Aura ID (с версии хранилища 2). `None` для записей,
+		/// перенесенных из версии 0: их блок регистрации неизвестен.
+		pub created: Option<BlockNumber>,
+	}
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+	use frame_support::{
+		assert_noop, assert_ok, 
+		traits::{ConstU128, ConstU32, Currency, Get, ReservableCurrency},
+		BoundedVec
+	};
+	use sp_core::{sr25519, Pair, H256};
+	use sp_runtime::{
+		traits::{BlakeTwo256, IdentityLookup},
+		BuildStorage, DispatchError,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	type Block = frame_system::mocking::MockBlock<Test>;
+
+	frame_support::construct_runtime!(
+		pub enum Test {
+			System: frame_system,
+			Balances: pallet_balances,
+			AuraIdentity: pallet,
+		}
+	);
+
+	// Конфигурация для тестов
+	pub struct TestMaxTrustees;
+	impl Get<u32> for TestMaxTrustees {
+		fn get() -> u32 { MAX_TRUSTEES }
+	}
+
+	pub struct TestRecoveryDeposit;
+	impl Get<u128> for TestRecoveryDeposit {
+		fn get() -> u128 { 100 }
+	}
+
+	frame_support::parameter_types! {
+		pub const TestRecoveryBond: u128 = 50;
+		pub const TestRecoveryBondSlash: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+		pub TestSlashBeneficiary: Option<u64> = None;
+		pub const TestRecoveryDelay: u32 = 100;
+		pub const TestMinRecoveryDelay: u32 = 10;
+		pub const TestMaxRecoveryDelay: u32 = 1_000;
+		pub const TestRecoveryLifetime: u32 = 50;
+		pub const TestShareComplaintPeriod: u32 = 20;
+		pub const TestMaxTrusteeships: u32 = 3;
+		pub const TestMaxKeyHistory: u32 = 2;
+		pub const TestMaxVerificationMethods: u32 = 2;
+		pub const TestMaxServices: u32 = 2;
+		pub const TestMaxControllers: u32 = 2;
+		pub const TestDocumentDepositBase: u128 = 10;
+		pub const TestDocumentByteDeposit: u128 = 1;
+	}
+
+	impl frame_system::Config for Test {
+		type BaseCallFilter = frame_support::traits::Everything;
+		type BlockWeights = ();
+		type BlockLength = ();
+		type DbWeight = ();
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeCall = RuntimeCall;
+		type Nonce = u64;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type Block = Block;
+		type RuntimeEvent = RuntimeEvent;
+		type BlockHashCount = frame_support::traits::ConstU64<250>;
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<u128>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ();
+		type OnSetCode = ();
+		type MaxConsumers = frame_support::traits::ConstU32<16>;
+	}
+
+	impl pallet_balances::Config for Test {
+		type Balance = u128;
+		type RuntimeEvent = RuntimeEvent;
+		type DustRemoval = ();
+		type ExistentialDeposit = ConstU128<1>;
+		type AccountStore = System;
+		type WeightInfo = ();
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type HoldIdentifier = ();
+		type FreezeIdentifier = ();
+		type MaxHolds = ();
+		type MaxFreezes = ();
+	}
+
+	impl Config for Test {
+		type RuntimeEvent = RuntimeEvent;
+		type Currency = Balances;
+		type MaxTrustees = TestMaxTrustees;
+		type MaxTrusteeships = TestMaxTrusteeships;
+		type RecoveryDeposit = TestRecoveryDeposit;
+		type RecoveryBond = TestRecoveryBond;
+		type RecoveryBondSlash = TestRecoveryBondSlash;
+		type SlashBeneficiary = TestSlashBeneficiary;
+		type DefaultRecoveryDelay = TestRecoveryDelay;
+		type MinRecoveryDelay = TestMinRecoveryDelay;
+		type MaxRecoveryDelay = TestMaxRecoveryDelay;
+		type RecoveryLifetime = TestRecoveryLifetime;
+		type ShareComplaintPeriod = TestShareComplaintPeriod;
+		type MaxKeyHistory = TestMaxKeyHistory;
+		type MaxVerificationMethods = TestMaxVerificationMethods;
+		type MaxServices = TestMaxServices;
+		type MaxControllers = TestMaxControllers;
+		type DocumentDepositBase = TestDocumentDepositBase;
+		type DocumentByteDeposit = TestDocumentByteDeposit;
+		type ForceOrigin = frame_system::EnsureRoot<u64>;
+		type DidLookup = IdentityLookup<[u8; 32]>;
+		type TrusteeLookup = IdentityLookup<Self::AccountId>;
+		type WeightInfo = ();
+	}
+
+	// СУЩЕСТВУЮЩИЕ ТЕСТЫ
+	#[test]
+	fn test_did_generation() {
+		let public_key = AuraPublicKey::Sr25519([1u8; 32]);
+		let did = Pallet::<Test>::generate_did(&1, &public_key, 0);
+
+		assert_eq!(did.len(), 32);
+		assert_ne!(&did[..], public_key.as_bytes());
+
+		// Алгоритм ключа входит в DID
+		assert_ne!(did, Pallet::<Test>::generate_did(&1, &AuraPublicKey::Ed25519([1u8; 32]), 0));
+
+		// Аккаунт и nonce входят в DID
+		assert_ne!(did, Pallet::<Test>::generate_did(&2, &public_key, 0));
+		assert_ne!(did, Pallet::<Test>::generate_did(&1, &public_key, 1));
+
+		// Схема воспроизводима вне цепи
+		let mut preimage = Vec::new();
+		preimage.push(11 << 2);
+		preimage.extend_from_slice(b"did:aura:v2");
+		preimage.extend_from_slice(&1u64.to_le_bytes());
+		preimage.push(0);
+		preimage.extend_from_slice(&[1u8; 32]);
+		preimage.extend_from_slice(&0u32.to_le_bytes());
+		assert_eq!(did, sp_io::hashing::blake2_256(&preimage));
+	}
+
+	#[test]
+	fn test_same_key_gives_distinct_dids() {
+		new_test_ext().execute_with(|| {
+			let pair = key_pair(7);
+			for account in [1, 2] {
+				assert_ok!(AuraIdentity::create_aura_id(
+					RuntimeOrigin::signed(account),
+					AuraPublicKey::from(pair.public()),
+					vec![],
+					key_proof(&pair, account)
+				));
+			}
+
+			let did_1 = AuraIdentity::get_aura_id(1).unwrap().did;
+			let did_2 = AuraIdentity::get_aura_id(2).unwrap().did;
+			assert_ne!(did_1, did_2);
+			assert_eq!(DidIndex::<Test>::get(did_1), Some(1));
+			assert_eq!(DidIndex::<Test>::get(did_2), Some(2));
+		});
+	}
+
+	#[test]
+	fn test_did_already_registered() {
+		new_test_ext().execute_with(|| {
+			let pair = key_pair(1);
+			let did = AuraIdentity::generate_did(&1, &AuraPublicKey::from(pair.public()), 0);
+			DidIndex::<Test>::insert(did, 5);
+
+			assert_noop!(
+				AuraIdentity::create_aura_id(
+					RuntimeOrigin::signed(1),
+					AuraPublicKey::from(pair.public()),
+					vec![],
+					key_proof(&pair, 1)
+				),
+				Error::<Test>::DidAlreadyRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn test_create_aura_id() {
+		new_test_ext().execute_with(|| {
+			let account_id = 1;
+			let pair = key_pair(2);
+			let public_key = AuraPublicKey::from(pair.public());
+			let recovery_config = vec![1, 2, 3];
+
+			assert_ok!(AuraIdentity::create_aura_id(
+				RuntimeOrigin::signed(account_id),
+				public_key,
+				recovery_config.clone(),
+				key_proof(&pair, account_id)
+			));
+
+			let record = AuraIdentity::get_aura_id(account_id).unwrap();
+			assert_eq!(record.public_key, public_key);
+			let expected_bounded: BoundedVec<u8, ConstU32<1024>> = recovery_config.try_into().unwrap();
+			assert_eq!(record.recovery_config, expected_bounded);
+		});
+	}
+
+	#[test]
+	fn test_duplicate_aura_id() {
+		new_test_ext().execute_with(|| {
+			let account_id = 1;
+			let pair = key_pair(3);
+			let public_key = AuraPublicKey::from(pair.public());
+			let recovery_config = vec![1, 2, 3];
+
+			assert_ok!(AuraIdentity::create_aura_id(
+				RuntimeOrigin::signed(account_id),
+				public_key,
+				recovery_config.clone(),
+				key_proof(&pair, account_id)
+			));
+
+			assert_noop!(
+				AuraIdentity::create_aura_id(
+					RuntimeOrigin::signed(account_id),
+					public_key,
+					recovery_config,
+					key_proof(&pair, account_id)
+				),
+				Error::<Test>::AuraIdAlreadyExists
+			);
+		});
+	}
+
+	#[test]
+	fn test_create_aura_id_requires_key_proof() {
+		new_test_ext().execute_with(|| {
+			let pair = key_pair(1);
+			let other = key_pair(2);
+
+			// Подпись чужим ключом
+			assert_noop!(
+				AuraIdentity::create_aura_id(
+					RuntimeOrigin::signed(1),
+					AuraPublicKey::from(pair.public()),
+					vec![],
+					key_proof(&other, 1)
+				),
+				Error::<Test>::InvalidKeyProof
+			);
+
+			// Подпись, выданная для другого аккаунта
+			assert_noop!(
+				AuraIdentity::create_aura_id(
+					RuntimeOrigin::signed(1),
+					AuraPublicKey::from(pair.public()),
+					vec![],
+					key_proof(&pair, 2)
+				),
+				Error::<Test>::InvalidKeyProof
+			);
+
+			assert_ok!(AuraIdentity::create_aura_id(
+				RuntimeOrigin::signed(1),
+				AuraPublicKey::from(pair.public()),
+				vec![],
+				key_proof(&pair, 1)
+			));
+			assert_eq!(AuraIdentity::key_proof_nonce(1), 1);
+		});
+	}
+
+	#[test]
+	fn test_create_aura_id_with_ed25519_and_ecdsa_keys() {
+		new_test_ext().execute_with(|| {
+			let payload = AuraIdentity::key_proof_payload(&1, 0);
+			let ed = sp_core::ed25519::Pair::from_seed(&[1u8; 32]);
+			assert_ok!(AuraIdentity::create_aura_id(
+				RuntimeOrigin::signed(1),
+				AuraPublicKey::from(ed.public()),
+				vec![],
+				ed.sign(&payload).into()
+			));
+
+			// Для ecdsa регистрируется сжатый публичный ключ
+			let payload = AuraIdentity::key_proof_payload(&2, 0);
+			let ecdsa = sp_core::ecdsa::Pair::from_seed(&[2u8; 32]);
+			assert_ok!(AuraIdentity::create_aura_id(
+				RuntimeOrigin::signed(2),
+				AuraPublicKey::from(ecdsa.public()),
+				vec![],
+				ecdsa.sign(&payload).into()
+			));
+
+			let record = AuraIdentity::get_aura_id(2).unwrap();
+			assert_eq!(record.public_key.key_type(), Some(VerificationKeyType::EcdsaSecp256k1));
+			assert_eq!(record.public_key.as_bytes(), ecdsa.public().as_ref());
+		});
+	}
+
+	#[test]
+	fn test_create_aura_id_with_p256_key() {
+		use p256::ecdsa::{signature::Signer, Signature, SigningKey};
+		new_test_ext().execute_with(|| {
+			let signing_key = SigningKey::from_slice(&[3u8; 32]).unwrap();
+			let point = signing_key.verifying_key().to_encoded_point(true);
+			let public_key = AuraPublicKey::P256(point.as_bytes().try_into().unwrap());
+			let sign = |account: u64| {
+				let payload = AuraIdentity::key_proof_payload(&account, AuraIdentity::key_proof_nonce(account));
+				let signature: Signature = signing_key.sign(&payload);
+				AuraSignature::P256(signature.to_bytes().as_slice().try_into().unwrap())
+			};
+
+			// Подпись другого алгоритма с теми же байтами недействительна
+			let AuraSignature::P256(raw) = sign(1) else { unreachable!() };
+			assert_noop!(
+				AuraIdentity::create_aura_id(
+					RuntimeOrigin::signed(1),
+					public_key,
+					vec![],
+					AuraSignature::Ed25519(raw)
+				),
+				Error::<Test>::InvalidKeyProof
+			);
+			assert_noop!(
+				AuraIdentity::create_aura_id(RuntimeOrigin::signed(1), public_key, vec![], sign(2)),
+				Error::<Test>::InvalidKeyProof
+			);
+
+			assert_ok!(AuraIdentity::create_aura_id(RuntimeOrigin::signed(1), public_key, vec![], sign(1)));
+			let record = AuraIdentity::get_aura_id(1).unwrap();
+			assert_eq!(record.public_key, public_key);
+			assert_eq!(record.did, AuraIdentity::generate_did(&1, &public_key, 0));
+
+			// Исторические подписи проверяются тем же алгоритмом
+			let message = b"signed document";
+			let signature: Signature = signing_key.sign(message);
+			let signature = AuraSignature::P256(signature.to_bytes().as_slice().try_into().unwrap());
+			assert!(AuraIdentity::verify_signature_at(&1, message, &signature, 1));
+			assert!(!AuraIdentity::verify_signature_at(&1, b"other document", &signature, 1));
+		});
+	}
+
+	#[test]
+	fn test_rotate_key() {
+		new_test_ext().execute_with(|| {
+			create_aura_id_for_account(1);
+			let did = AuraIdentity::get_aura_id(1).unwrap().did;
+			let old_key = AuraPublicKey::from(key_pair(1).public());
+			let new_pair = key_pair(20);
+			let new_key = AuraPublicKey::from(new_pair.public());
+
+			let authorization = rotation_authorization(&key_pair(1), 1, &new_key);
+
+			// Новый ключ должен доказать владение
+			assert_noop!(
+				AuraIdentity::rotate_key(
+					RuntimeOrigin::signed(1),
+					new_key,
+					key_proof(&key_pair(21), 1),
+					authorization.clone()
+				),
+				Error::<Test>::InvalidKeyProof
+			);
+			// Смену должен разрешить текущий ключ, а не новый
+			assert_noop!(
+				AuraIdentity::rotate_key(
+					RuntimeOrigin::signed(1),
+					new_key,
+					key_proof(&new_pair, 1),
+					rotation_authorization(&new_pair, 1, &new_key)
+				),
+				Error::<Test>::InvalidRotationAuthorization
+			);
+			// Разрешение привязано к новому ключу
+			assert_noop!(
+				AuraIdentity::rotate_key(
+					RuntimeOrigin::signed(1),
+					AuraPublicKey::from(key_pair(21).public()),
+					key_proof(&key_pair(21), 1),
+					authorization.clone()
+				),
+				Error::<Test>::InvalidRotationAuthorization
+			);
+			assert_noop!(
+				AuraIdentity::rotate_key(
+					RuntimeOrigin::signed(1),
+					old_key,
+					key_proof(&key_pair(1), 1),
+					rotation_authorization(&key_pair(1), 1, &old_key)
+				),
+				Error::<Test>::KeyUnchanged
+			);
+
+			System::set_block_number(10);
+			assert_ok!(AuraIdentity::rotate_key(
+				RuntimeOrigin::signed(1),
+				new_key,
+				key_proof(&new_pair, 1),
+				authorization.clone()
+			));
+
+			let record = AuraIdentity::get_aura_id(1).unwrap();
+			assert_eq!(record.did, did);
+			assert_eq!(record.public_key, new_key);
+			assert_eq!(
+				AuraIdentity::key_history(1).into_inner(),
+				vec![KeyRecord { public_key: old_key, valid_from: 0, valid_until: 10 }]
+			);
+			System::assert_last_event(
+				Event::KeyRotated { account: 1, did, old_key, new_key }.into()
+			);
+		});
+	}
+
+	#[test]
+	fn test_verify_historical_signature() {
+		new_test_ext().execute_with(|| {
+			create_aura_id_for_account(1);
+			let old_pair = key_pair(1);
+			let new_pair = key_pair(20);
+
+			System::set_block_number(10);
+			assert_ok!(AuraIdentity::rotate_key(
+				RuntimeOrigin::signed(1),
+				AuraPublicKey::from(new_pair.public()),
+				key_proof(&new_pair, 1),
+				rotation_authorization(&old_pair, 1, &AuraPublicKey::from(new_pair.public()))
+			));
+
+			let message = b"signed document";
+			let old_signature: AuraSignature = old_pair.sign(message).into();
+			let new_signature: AuraSignature = new_pair.sign(message).into();
+
+			assert!(AuraIdentity::verify_signature_at(&1, message, &old_signature, 5));
+			assert!(!AuraIdentity::verify_signature_at(&1, message, &old_signature, 10));
+			assert!(AuraIdentity::verify_signature_at(&1, message, &new_signature, 10));
+			assert!(!AuraIdentity::verify_signature_at(&1, message, &new_signature, 5));
+
+			// История ограничена: самый старый ключ вытесняется
+			let mut current = new_pair.clone();
+			for (block, seed) in [(20, 21u8), (30, 22u8)] {
+				System::set_block_number(block);
+				let pair = key_pair(seed);
+				let key = AuraPublicKey::from(pair.public());
+				assert_ok!(AuraIdentity::rotate_key(
+					RuntimeOrigin::signed(1),
+					key,
+					key_proof(&pair, 1),
+					rotation_authorization(&current, 1, &key)
+				));
+				current = pair;
+			}
+			assert_eq!(AuraIdentity::key_history(1).len(), 2);
+			assert_eq!(AuraIdentity::key_at(&1, 5), None);
+			assert_eq!(AuraIdentity::key_at(&1, 15), Some(AuraPublicKey::from(new_pair.public())));
+		});
+	}
+
+	#[test]
+	fn test_key_at_before_registration() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(10);
+			create_aura_id_for_account(1);
+			let pair = key_pair(1);
+			let signature: AuraSignature = pair.sign(b"signed document").into();
+
+			// До регистрации у Aura ID не было ключа
+			assert_eq!(AuraIdentity::key_at(&1, 9), None);
+			assert!(!AuraIdentity::verify_signature_at(&1, b"signed document", &signature, 9));
+			assert_eq!(AuraIdentity::key_at(&1, 10), Some(AuraPublicKey::from(pair.public())));
+		});
+	}
+
+	#[test]
+	fn test_verification_methods() {
+		new_test_ext().execute_with(|| {
+			create_aura_id_for_account(1);
+
+			assert_noop!(
+				AuraIdentity::add_verification_method(
+					RuntimeOrigin::signed(1),
+					b"keys-1".to_vec(),
+					VerificationKeyType::Ed25519,
+					vec![7u8; 32],
+					None,
+					vec![]
+				),
+				Error::<Test>::InvalidDocumentElement
+			);
+
+			assert_ok!(AuraIdentity::add_verification_method(
+				RuntimeOrigin::signed(1),
+				b"keys-1".to_vec(),
+				VerificationKeyType::Ed25519,
+				vec![7u8; 32],
+				None,
+				vec![VerificationPurpose::AssertionMethod, VerificationPurpose::Authentication]
+			));
+
+			let fragment: Fragment = b"keys-1".to_vec().try_into().unwrap();
+			let method = AuraIdentity::verification_method(1, &fragment).unwrap();
+			assert_eq!(
+				method.purposes.into_inner(),
+				vec![VerificationPurpose::Authentication, VerificationPurpose::AssertionMethod]
+			);
+			assert!(method.deposit > 10);
+			assert_eq!(Balances::reserved_balance(1), method.deposit);
+			assert_eq!(AuraIdentity::document_version(1).version_id, 1);
+
+			assert_noop!(
+				AuraIdentity::add_verification_method(
+					RuntimeOrigin::signed(1),
+					b"keys-1".to_vec(),
+					VerificationKeyType::X25519,
+					vec![8u8; 32],
+					None,
+					vec![VerificationPurpose::KeyAgreement]
+				),
+				Error::<Test>::VerificationMethodAlreadyExists
+			);
+
+			assert_ok!(AuraIdentity::remove_verification_method(
+				RuntimeOrigin::signed(1),
+				b"keys-1".to_vec()
+			));
+			assert!(AuraIdentity::verification_method(1, &fragment).is_none());
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(AuraIdentity::document_version(1).version_id, 2);
+		});
+	}
+
+	#[test]
+	fn test_services_and_controllers() {
+		new_test_ext().execute_with(|| {
+			create_aura_id_for_account(1);
+			create_aura_id_for_account(2);
+			let controller = AuraIdentity::get_aura_id(2).unwrap().did;
+
+			for fragment in [b"svc-1", b"svc-2"] {
+				assert_ok!(AuraIdentity::add_service(
+					RuntimeOrigin::signed(1),
+					fragment.to_vec(),
+					b"LinkedDomains".to_vec(),
+					b"https://aura.example".to_vec()
+				));
+			}
+			assert_noop!(
+				AuraIdentity::add_service(
+					RuntimeOrigin::signed(1),
+					b"svc-3".to_vec(),
+					b"LinkedDomains".to_vec(),
+					b"https://aura.example".to_vec()
+				),
+				Error::<Test>::TooManyServices
+			);
+
+			assert_noop!(
+				AuraIdentity::add_controller(RuntimeOrigin::signed(1), [0u8; 32]),
+				Error::<Test>::ControllerNotFound
+			);
+			assert_ok!(AuraIdentity::add_controller(RuntimeOrigin::signed(1), controller));
+			assert_eq!(
+				AuraIdentity::controller_deposit(1, controller),
+				Some(AuraIdentity::document_deposit(32))
+			);
+
+			// Документ целиком для resolver-а
+			let did = AuraIdentity::get_aura_id(1).unwrap().did;
+			let document = AuraIdentity::did_document(&did).unwrap();
+			assert_eq!(document.record.did, did);
+			assert_eq!(document.services.len(), 2);
+			assert_eq!(document.controllers, vec![controller]);
+			assert_eq!(document.version, AuraIdentity::document_version(1));
+			assert_eq!(AuraIdentity::did_document(&[0u8; 32]), None);
+
+			assert_ok!(AuraIdentity::remove_service(RuntimeOrigin::signed(1), b"svc-1".to_vec()));
+			assert_ok!(AuraIdentity::remove_service(RuntimeOrigin::signed(1), b"svc-2".to_vec()));
+			assert_ok!(AuraIdentity::remove_controller(RuntimeOrigin::signed(1), controller));
+			assert_eq!(Balances::reserved_balance(1), 0);
+		});
+	}
+
+	// НОВЫЕ ТЕСТЫ ДЛЯ SOCIAL RECOVERY
+	#[test]
+	fn test_setup_recovery() {
+		new_test_ext().execute_with(|| {
+			// Создаем аккаунты
+			let alice = 1;
+			let bob = 2;
+			let charlie = 3;
+			
+			// Создаем Aura ID для всех участников
+			create_aura_id_for_account(alice);
+			create_aura_id_for_account(bob);
+			create_aura_id_for_account(charlie);
+			
+			// Настраиваем recovery систему для Alice
+			assert_ok!(AuraIdentity::setup_recovery(
+				RuntimeOrigin::signed(alice),
+				2,
+				vec![bob, charlie],
+				None
+			));
+			
+			// Проверяем, что конфигурация создана
+			assert!(AuraIdentity::get_recovery_config(alice).is_some());
+			
+			let config = AuraIdentity::get_recovery_config(alice).unwrap();
+			assert_eq!(config.threshold, 2);
+			assert_eq!(config.total_trustees, 2);
+			assert!(config.active);
+		});
+	}
+
+	#[test]
+	fn test_setup_recovery_rejects_duplicate_trustee() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=3 {
+				create_aura_id_for_account(account);
+			}
+			
+			// Повторный контакт не должен засчитываться в `total_trustees` дважды
+			assert_noop!(
+				AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 2], None),
+				Error::<Test>::DuplicateTrustee
+			);
+			assert_noop!(
+				AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3, 2], None),
+				Error::<Test>::DuplicateTrustee
+			);
+			
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], None));
+			assert_noop!(
+				AuraIdentity::add_trustee(RuntimeOrigin::signed(1), 3),
+				Error::<Test>::DuplicateTrustee
+			);
+		});
+	}
+
+	#[test]
+	fn test_setup_recovery_rejects_self_trustee() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=4 {
+				create_aura_id_for_account(account);
+			}
+			
+			assert_noop!(
+				AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![1, 2], None),
+				Error::<Test>::SelfTrustee
+			);
+			
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], None));
+			assert_noop!(
+				AuraIdentity::add_trustee(RuntimeOrigin::signed(1), 1),
+				Error::<Test>::SelfTrustee
+			);
+		});
+	}
+
+	#[test]
+	fn test_add_remove_trustee() {
+		new_test_ext().execute_with(|| {
+			let alice = 1;
+			let bob = 2;
+			let charlie = 3;
+			let dave = 4;
+			
+			create_aura_id_for_account(alice);
+			create_aura_id_for_account(bob);
+			create_aura_id_for_account(charlie);
+			create_aura_id_for_account(dave);
+			
+			// Сначала настраиваем базовую систему
+			assert_ok!(AuraIdentity::setup_recovery(
+				RuntimeOrigin::signed(alice),
+				2,
+				vec![bob, charlie],
+				None
+			));
+			
+			// Добавляем нового доверенного контакта
+			assert_ok!(AuraIdentity::add_trustee(
+				RuntimeOrigin::signed(alice),
+				dave
+			));
+			
+			// Проверяем, что контакт добавлен
+			assert!(AuraIdentity::get_trustee_share(alice, dave).is_some());
+			
+			let config = AuraIdentity::get_recovery_config(alice).unwrap();
+			assert_eq!(config.total_trustees, 3);
+			
+			// Удаляем доверенный контакт
+			assert_ok!(AuraIdentity::remove_trustee(
+				RuntimeOrigin::signed(alice),
+				dave
+			));
+			
+			// Проверяем, что контакт удален
+			assert!(AuraIdentity::get_trustee_share(alice, dave).is_none());
+			
+			// Порог не снижается: последний контакт сверх порога уже удален
+			assert_noop!(
+				AuraIdentity::remove_trustee(RuntimeOrigin::signed(alice), bob),
+				Error::<Test>::TooFewTrustees
+			);
+			let config = AuraIdentity::get_recovery_config(alice).unwrap();
+			assert_eq!((config.threshold, config.total_trustees), (2, 2));
+		});
+	}
+
+	#[test]
+	fn test_trustees_frozen_during_recovery() {
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			create_aura_id_for_account(5);
+			
+			// Укравший ключ не может сменить контактов, пока идет восстановление
+			assert_noop!(
+				AuraIdentity::add_trustee(RuntimeOrigin::signed(1), 5),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			assert_noop!(
+				AuraIdentity::remove_trustee(RuntimeOrigin::signed(1), 2),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			assert_noop!(
+				AuraIdentity::remove_recovery(RuntimeOrigin::signed(1)),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			
+			// После отмены или истечения запроса изменения снова доступны
+			let request = AuraIdentity::get_active_recovery(1).unwrap();
+			System::set_block_number(AuraIdentity::recovery_expires_at(&request));
+			assert_ok!(AuraIdentity::add_trustee(RuntimeOrigin::signed(1), 5));
+			
+			assert_ok!(AuraIdentity::remove_expired_recovery(RuntimeOrigin::signed(5), 1));
+			assert_ok!(AuraIdentity::remove_trustee(RuntimeOrigin::signed(1), 2));
+		});
+	}
+
+	/// Alice (1) делит секрет между Bob (2, индекс 1) и Charlie (3, индекс 2)
+	/// с порогом 2. Возвращает коэффициенты многочлена.
+	fn setup_shares() -> Vec<p256::Scalar> {
+		for account in 1..=3 {
+			create_aura_id_for_account(account);
+		}
+		assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], None));
+		let coefficients = vec![vss::dealer::scalar(1u8), vss::dealer::scalar(2u8)];
+		assert_ok!(AuraIdentity::upload_shares(
+			RuntimeOrigin::signed(1),
+			vss::dealer::commitments(&coefficients),
+			vec![(2, b"encrypted for bob".to_vec()), (3, b"encrypted for charlie".to_vec())],
+		));
+		coefficients
+	}
+
+	#[test]
+	fn test_upload_and_prove_shares() {
+		new_test_ext().execute_with(|| {
+			let coefficients = setup_shares();
+			System::assert_last_event(Event::SharesUploaded { account: 1, threshold: 2 }.into());
+			assert_eq!(AuraIdentity::get_trustee_share(1, 3).unwrap().share.to_vec(), b"encrypted for charlie".to_vec());
+			assert_eq!(AuraIdentity::share_state(1, 3).unwrap().index, 2);
+			
+			// Обязательств должно быть столько же, сколько порог, и все - точки кривой
+			let commitments = vss::dealer::commitments(&coefficients);
+			let shares = || vec![(2, vec![1u8]), (3, vec![2u8])];
+			assert_noop!(
+				AuraIdentity::upload_shares(RuntimeOrigin::signed(1), commitments[..1].to_vec(), shares()),
+				Error::<Test>::InvalidShareCommitments
+			);
+			assert_noop!(
+				AuraIdentity::upload_shares(RuntimeOrigin::signed(1), vec![commitments[0], [0u8; 33]], shares()),
+				Error::<Test>::InvalidShareCommitments
+			);
+			// Шар нужен каждому контакту
+			assert_noop!(
+				AuraIdentity::upload_shares(RuntimeOrigin::signed(1), commitments.clone(), vec![(2, vec![1u8])]),
+				Error::<Test>::IncompleteShares
+			);
+			assert_noop!(
+				AuraIdentity::upload_shares(RuntimeOrigin::signed(1), commitments.clone(), vec![(2, vec![1u8]), (2, vec![2u8])]),
+				Error::<Test>::IncompleteShares
+			);
+			
+			// Bob доказывает знание своего шара
+			let bob_share = vss::dealer::share(&coefficients, 1);
+			let proof = vss::dealer::prove(&bob_share, &AuraIdentity::share_proof_context(&1, &2, 1));
+			assert_ok!(AuraIdentity::prove_share(RuntimeOrigin::signed(2), 1, proof.clone()));
+			assert_eq!(AuraIdentity::share_state(1, 2).unwrap().status, ShareStatus::Verified);
+			System::assert_last_event(Event::ShareVerified { account: 1, trustee: 2 }.into());
+			
+			// Доказательство привязано к контакту и индексу
+			assert_noop!(
+				AuraIdentity::prove_share(RuntimeOrigin::signed(3), 1, proof),
+				Error::<Test>::InvalidShareProof
+			);
+			
+			// Удаление восстановления удаляет и шары
+			assert_ok!(AuraIdentity::remove_recovery(RuntimeOrigin::signed(1)));
+			assert!(AuraIdentity::share_commitments(1).is_none());
+			assert!(AuraIdentity::share_states(&1).is_empty());
+		});
 	}
 
-	impl frame_system::Config for Test {
-		type BaseCallFilter = frame_support::traits::Everything;
-		type BlockWeights = ();
-		type BlockLength = ();
-		type DbWeight = ();
-		type RuntimeOrigin = RuntimeOrigin;
-		type RuntimeCall = RuntimeCall;
-		type Nonce = u64;
-		type Hash = H256;
-		type Hashing = BlakeTwo256;
-		type AccountId = u64;
-		type Lookup = IdentityLookup<Self::AccountId>;
-		type Block = Block;
-		type RuntimeEvent = RuntimeEvent;
-		type BlockHashCount = frame_support::traits::ConstU64<250>;
-		type Version = ();
-		type PalletInfo = PalletInfo;
-		type AccountData = pallet_balances::AccountData<u128>;
-		type OnNewAccount = ();
-		type OnKilledAccount = ();
-		type SystemWeightInfo = ();
-		type SS58Prefix = ();
-		type OnSetCode = ();
-		type MaxConsumers = frame_support::traits::ConstU32<16>;
+	#[test]
+	fn test_share_complaint() {
+		new_test_ext().execute_with(|| {
+			let coefficients = setup_shares();
+			
+			// Расшифрованный шар Charlie не сходится с обязательствами - жалоба
+			assert_ok!(AuraIdentity::dispute_share(RuntimeOrigin::signed(3), 1));
+			let respond_by = 1 + TestShareComplaintPeriod::get() as u64;
+			System::assert_last_event(Event::ShareDisputed { account: 1, trustee: 3, respond_by }.into());
+			assert_noop!(
+				AuraIdentity::dispute_share(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::ShareNotPending
+			);
+			assert_noop!(
+				AuraIdentity::reveal_share(RuntimeOrigin::signed(1), 2, [0u8; 32]),
+				Error::<Test>::ShareNotDisputed
+			);
+			
+			// Некорректный шар не проходит проверку по обязательствам
+			let wrong = vss::dealer::share_bytes(&vss::dealer::share(&coefficients, 1));
+			assert_noop!(
+				AuraIdentity::reveal_share(RuntimeOrigin::signed(1), 3, wrong),
+				Error::<Test>::InvalidShare
+			);
+			
+			// Владелец отвечает на жалобу Charlie в последний блок срока
+			assert_ok!(AuraIdentity::dispute_share(RuntimeOrigin::signed(2), 1));
+			System::set_block_number(respond_by);
+			let share = vss::dealer::share_bytes(&vss::dealer::share(&coefficients, 2));
+			assert_ok!(AuraIdentity::reveal_share(RuntimeOrigin::signed(1), 3, share));
+			System::assert_last_event(Event::ShareRevealed { account: 1, trustee: 3, share }.into());
+			assert_eq!(AuraIdentity::share_state(1, 3).unwrap().status, ShareStatus::Verified);
+			assert!(AuraIdentity::invalid_shares(&1).is_empty());
+			
+			// На жалобу Bob владелец не ответил вовремя - шар некорректен,
+			// и раскрыть его уже нельзя
+			System::set_block_number(respond_by + 1);
+			assert_eq!(AuraIdentity::invalid_shares(&1), vec![2]);
+			let share = vss::dealer::share_bytes(&vss::dealer::share(&coefficients, 1));
+			assert_noop!(
+				AuraIdentity::reveal_share(RuntimeOrigin::signed(1), 2, share),
+				Error::<Test>::ShareComplaintExpired
+			);
+		});
 	}
 
-	impl pallet_balances::Config for Test {
-		type Balance = u128;
-		type RuntimeEvent = RuntimeEvent;
-		type DustRemoval = ();
-		type ExistentialDeposit = ConstU128<1>;
-		type AccountStore = System;
-		type WeightInfo = ();
-		type MaxLocks = ();
-		type MaxReserves = ();
-		type ReserveIdentifier = [u8; 8];
-		type HoldIdentifier = ();
-		type FreezeIdentifier = ();
-		type MaxHolds = ();
-		type MaxFreezes = ();
+	/// Политика Alice: (семья - вес 2, друзья - вес 1, нужно 2) И организация.
+	/// Семья - Bob (2), друзья - Charlie (3) и Eve (5), организация - Ferdie (6).
+	fn policy_nodes() -> Vec<PolicyNode<u64>> {
+		vec![
+			PolicyNode::Trustee(2),
+			PolicyNode::Trustee(3),
+			PolicyNode::Trustee(5),
+			PolicyNode::Trustee(6),
+			PolicyNode::AtLeast { threshold: 2, children: vec![(0, 2), (1, 1), (2, 1)].try_into().unwrap() },
+			PolicyNode::All(vec![4, 3].try_into().unwrap()),
+		]
+	}
+
+	#[test]
+	fn test_recovery_policy_weighted_tree() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=6 {
+				create_aura_id_for_account(account);
+			}
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3, 5, 6], None));
+			for trustee in [2, 3, 5, 6] {
+				assert_ok!(AuraIdentity::accept_trusteeship(RuntimeOrigin::signed(trustee), 1));
+			}
+			assert_ok!(AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(policy_nodes())));
+			System::assert_last_event(Event::RecoveryPolicySet { account: 1 }.into());
+			
+			// Контакт из политики нельзя удалить, пока политика ссылается на него
+			assert_noop!(
+				AuraIdentity::remove_trustee(RuntimeOrigin::signed(1), 6),
+				Error::<Test>::TrusteeInPolicy
+			);
+			
+			assert_ok!(AuraIdentity::initiate_recovery(RuntimeOrigin::signed(4), 1, AuraPublicKey::Sr25519([9u8; 32])));
+			System::set_block_number(1 + TestRecoveryDelay::get() as u64);
+			
+			// Организация вместе с одним другом набирает порог конфигурации,
+			// но не политику: вес друга 1 из нужных 2
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(6), 1));
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(3), 1));
+			assert!(!AuraIdentity::recovery_status(&1).unwrap().executable);
+			assert_noop!(
+				AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::InsufficientShares
+			);
+			
+			// Второй друг добирает вес
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(5), 1));
+			assert!(AuraIdentity::recovery_status(&1).unwrap().executable);
+			assert_ok!(AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1));
+			assert_eq!(AuraIdentity::get_aura_id(1).unwrap().public_key, AuraPublicKey::Sr25519([9u8; 32]));
+		});
+	}
+
+	#[test]
+	fn test_decline_trusteeship_in_policy() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=7 {
+				create_aura_id_for_account(account);
+			}
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3, 5, 6, 7], None));
+			assert_ok!(AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(policy_nodes())));
+			
+			// Контакт из политики не может отказаться, иначе ее лист не выполнится
+			assert_noop!(
+				AuraIdentity::decline_trusteeship(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::TrusteeInPolicy
+			);
+			
+			// Контакт вне политики отказывается как обычно
+			assert_ok!(AuraIdentity::decline_trusteeship(RuntimeOrigin::signed(7), 1));
+			assert!(AuraIdentity::get_trustee_share(1, 7).is_none());
+			
+			// После снятия политики отказ возможен
+			assert_ok!(AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), None));
+			assert_ok!(AuraIdentity::decline_trusteeship(RuntimeOrigin::signed(2), 1));
+			assert_eq!(AuraIdentity::get_recovery_config(1).unwrap().total_trustees, 3);
+		});
+	}
+
+	#[test]
+	fn test_recovery_policy_validation() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=6 {
+				create_aura_id_for_account(account);
+			}
+			assert_noop!(
+				AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(policy_nodes())),
+				Error::<Test>::RecoveryNotConfigured
+			);
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3, 5, 6], None));
+			
+			let invalid = [
+				// Организация одна может восстановить аккаунт
+				vec![PolicyNode::Trustee(6)],
+				// «Семья ИЛИ организация» - тоже один контакт
+				vec![
+					PolicyNode::Trustee(2),
+					PolicyNode::Trustee(6),
+					PolicyNode::Any(vec![0, 1].try_into().unwrap()),
+				],
+				// Ссылка вперед
+				vec![PolicyNode::All(vec![1].try_into().unwrap()), PolicyNode::Trustee(2)],
+				// Узел не входит в дерево
+				vec![
+					PolicyNode::Trustee(2),
+					PolicyNode::Trustee(3),
+					PolicyNode::Trustee(5),
+					PolicyNode::All(vec![0, 1].try_into().unwrap()),
+				],
+				// Повторный контакт
+				vec![
+					PolicyNode::Trustee(2),
+					PolicyNode::Trustee(2),
+					PolicyNode::All(vec![0, 1].try_into().unwrap()),
+				],
+				// Недостижимый порог
+				vec![
+					PolicyNode::Trustee(2),
+					PolicyNode::Trustee(3),
+					PolicyNode::AtLeast { threshold: 3, children: vec![(0, 1), (1, 1)].try_into().unwrap() },
+				],
+			];
+			for nodes in invalid {
+				assert_noop!(
+					AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(nodes)),
+					Error::<Test>::InvalidRecoveryPolicy
+				);
+			}
+			
+			// Лист политики должен быть доверенным контактом
+			let mut nodes = policy_nodes();
+			nodes[3] = PolicyNode::Trustee(7);
+			assert_noop!(
+				AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(nodes)),
+				Error::<Test>::TrusteeNotFound
+			);
+			
+			assert_eq!(RecoveryPolicy { nodes: policy_nodes().try_into().unwrap() }.min_approvals(), Some(2));
+			assert_ok!(AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(policy_nodes())));
+			assert_ok!(AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), None));
+			assert!(AuraIdentity::recovery_policy(1).is_none());
+			System::assert_last_event(Event::RecoveryPolicyRemoved { account: 1 }.into());
+		});
+	}
+
+	#[test]
+	fn test_submit_recovery_share() {
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(3), 1));
+			
+			let request = AuraIdentity::get_active_recovery(1).unwrap();
+			assert_eq!(request.submitted_shares, 2);
+			assert!(AuraIdentity::get_recovery_vouch(1, 2).is_some());
+			assert!(AuraIdentity::get_recovery_vouch(1, 3).is_some());
+			
+			System::assert_last_event(
+				Event::RecoveryShareProvided { lost_account: 1, trustee: 3 }.into()
+			);
+		});
+	}
+
+	#[test]
+	fn test_submit_recovery_share_rejects_invalid_callers() {
+		new_test_ext().execute_with(|| {
+			// Запрос еще не создан
+			assert_noop!(
+				AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::RecoveryNotActive
+			);
+			
+			setup_active_recovery();
+			
+			// Dave не является доверенным контактом Alice
+			assert_noop!(
+				AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::NotAuthorized
+			);
+			
+			// Повторное подтверждение запрещено
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			assert_noop!(
+				AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::AlreadyVouched
+			);
+		});
+	}
+
+	#[test]
+	fn test_execute_recovery() {
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			let did = AuraIdentity::get_aura_id(1).unwrap().did;
+			
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(3), 1));
+			
+			// Период ожидания еще не прошел
+			assert_noop!(
+				AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::DelayPeriodNotPassed
+			);
+			
+			System::set_block_number(1 + TestRecoveryDelay::get() as u64);
+			
+			// Выполнить может только инициатор
+			assert_noop!(
+				AuraIdentity::execute_recovery(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::NotAuthorized
+			);
+			assert_ok!(AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1));
+			
+			let record = AuraIdentity::get_aura_id(1).unwrap();
+			assert_eq!(record.public_key, AuraPublicKey::Sr25519([9u8; 32]));
+			assert_eq!(record.did, did);
+			assert_eq!(DidIndex::<Test>::get(did), Some(1));
+			assert!(AuraIdentity::get_active_recovery(1).unwrap().completed);
+			assert!(AuraIdentity::get_recovery_vouch(1, 2).is_none());
+			assert!(!AuraIdentity::recovery_in_progress(&1));
+			
+			// Залог инициатора возвращен
+			assert_eq!(Balances::reserved_balance(4), 0);
+			System::assert_last_event(
+				Event::RecoveryExecuted { lost_account: 1, new_account: 4 }.into()
+			);
+			
+			// Завершенный запрос нельзя выполнить или отменить повторно
+			assert_noop!(
+				AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::RecoveryAlreadyCompleted
+			);
+			assert_noop!(
+				AuraIdentity::cancel_recovery(RuntimeOrigin::signed(1)),
+				Error::<Test>::RecoveryAlreadyCompleted
+			);
+			
+			// Новый запрос заменяет завершенный
+			assert_ok!(AuraIdentity::initiate_recovery(RuntimeOrigin::signed(4), 1, AuraPublicKey::Sr25519([8u8; 32])));
+			assert!(!AuraIdentity::get_active_recovery(1).unwrap().completed);
+			assert_eq!(Balances::reserved_balance(4), TestRecoveryBond::get());
+		});
+	}
+
+	#[test]
+	fn test_execute_recovery_requires_threshold() {
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			System::set_block_number(1 + TestRecoveryDelay::get() as u64);
+			
+			assert_noop!(
+				AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::InsufficientShares
+			);
+		});
+	}
+
+	#[test]
+	fn test_cancel_recovery_slashes_initiator() {
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			assert_eq!(Balances::reserved_balance(4), 50);
+			
+			// Отменить может только владелец
+			assert_noop!(
+				AuraIdentity::cancel_recovery(RuntimeOrigin::signed(4)),
+				Error::<Test>::RecoveryNotActive
+			);
+			
+			let owner_free = Balances::free_balance(1);
+			assert_ok!(AuraIdentity::cancel_recovery(RuntimeOrigin::signed(1)));
+			
+			// Половина залога ушла владельцу, остаток возвращен инициатору
+			assert_eq!(Balances::reserved_balance(4), 0);
+			assert_eq!(Balances::free_balance(4), 1_000 - 25);
+			assert_eq!(Balances::free_balance(1), owner_free + 25);
+			assert!(AuraIdentity::get_active_recovery(1).is_none());
+			assert!(AuraIdentity::get_recovery_vouch(1, 2).is_none());
+			
+			System::assert_last_event(
+				Event::RecoveryCancelled { account: 1, requesting_account: 4, slashed: 25 }.into()
+			);
+		});
+	}
+
+	/// Alice (1) регистрирует резервный ключ с периодом ожидания 500 блоков
+	fn setup_backup_key() -> sr25519::Pair {
+		create_aura_id_for_account(1);
+		create_aura_id_for_account(4);
+		let backup = key_pair(100);
+		assert_ok!(AuraIdentity::set_backup_key(
+			RuntimeOrigin::signed(1),
+			AuraPublicKey::from(backup.public()),
+			key_proof(&backup, 1),
+			Some(500),
+		));
+		backup
+	}
+
+	fn backup_signature(backup: &sr25519::Pair, new_public_key: &AuraPublicKey, requesting_account: u64) -> AuraSignature {
+		let nonce = AuraIdentity::key_proof_nonce(1);
+		backup.sign(&AuraIdentity::backup_recovery_payload(&1, new_public_key, &requesting_account, nonce)).into()
+	}
+
+	#[test]
+	fn test_backup_key_recovery() {
+		new_test_ext().execute_with(|| {
+			let backup = setup_backup_key();
+			System::assert_last_event(Event::BackupKeySet { account: 1, delay_period: 500 }.into());
+			
+			// Доверенные контакты не настроены - резервный ключ работает сам по себе
+			assert!(AuraIdentity::get_recovery_config(1).is_none());
+			let new_key = AuraPublicKey::Sr25519([9u8; 32]);
+			let signature = backup_signature(&backup, &new_key, 4);
+			
+			// Подпись привязана к инициатору
+			assert_noop!(
+				AuraIdentity::initiate_backup_recovery(RuntimeOrigin::signed(5), 1, new_key, signature.clone()),
+				Error::<Test>::InvalidBackupSignature
+			);
+			assert_ok!(AuraIdentity::initiate_backup_recovery(RuntimeOrigin::signed(4), 1, new_key, signature));
+			assert!(AuraIdentity::recovery_in_progress(&1));
+			
+			// Действует период ожидания резервного ключа, а не период по умолчанию
+			System::set_block_number(1 + TestRecoveryDelay::get() as u64);
+			assert_noop!(
+				AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::DelayPeriodNotPassed
+			);
+			
+			System::set_block_number(501);
+			assert!(AuraIdentity::recovery_status(&1).unwrap().executable);
+			assert_ok!(AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1));
+			assert_eq!(AuraIdentity::get_aura_id(1).unwrap().public_key, new_key);
+			assert!(!BackupApprovals::<Test>::contains_key(1));
+			assert_eq!(Balances::reserved_balance(4), 0);
+		});
+	}
+
+	#[test]
+	fn test_backup_key_replaces_unvouched_recovery() {
+		new_test_ext().execute_with(|| {
+			let backup = setup_backup_key();
+			for account in 2..=3 {
+				create_aura_id_for_account(account);
+			}
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], None));
+			assert_ok!(AuraIdentity::accept_trusteeship(RuntimeOrigin::signed(2), 1));
+			assert_ok!(AuraIdentity::accept_trusteeship(RuntimeOrigin::signed(3), 1));
+			
+			// Запрос без подтверждений не мешает восстановлению резервным ключом
+			assert_ok!(AuraIdentity::initiate_recovery(RuntimeOrigin::signed(5), 1, AuraPublicKey::Sr25519([8u8; 32])));
+			let new_key = AuraPublicKey::Sr25519([9u8; 32]);
+			let signature = backup_signature(&backup, &new_key, 4);
+			assert_ok!(AuraIdentity::initiate_backup_recovery(RuntimeOrigin::signed(4), 1, new_key, signature));
+			System::assert_has_event(
+				Event::RecoveryCancelled { account: 1, requesting_account: 5, slashed: 25 }.into()
+			);
+			assert_eq!(Balances::reserved_balance(5), 0);
+			assert_eq!(AuraIdentity::get_active_recovery(1).unwrap().requesting_account, 4);
+			
+			// Одобренный резервным ключом запрос не вытесняется
+			let other_key = AuraPublicKey::Sr25519([7u8; 32]);
+			assert_noop!(
+				AuraIdentity::initiate_backup_recovery(
+					RuntimeOrigin::signed(6),
+					1,
+					other_key,
+					backup_signature(&backup, &other_key, 6)
+				),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			
+			// Как и запрос, поддержанный контактом
+			assert_ok!(AuraIdentity::cancel_recovery(RuntimeOrigin::signed(1)));
+			assert_ok!(AuraIdentity::initiate_recovery(RuntimeOrigin::signed(5), 1, AuraPublicKey::Sr25519([8u8; 32])));
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			assert_noop!(
+				AuraIdentity::initiate_backup_recovery(
+					RuntimeOrigin::signed(4),
+					1,
+					new_key,
+					backup_signature(&backup, &new_key, 4)
+				),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+		});
+	}
+
+	#[test]
+	fn test_owner_vetoes_backup_recovery() {
+		new_test_ext().execute_with(|| {
+			let reserved = Balances::reserved_balance(1);
+			let backup = setup_backup_key();
+			assert_eq!(Balances::reserved_balance(1), reserved + TestRecoveryDeposit::get());
+			
+			let new_key = AuraPublicKey::Sr25519([9u8; 32]);
+			let signature = backup_signature(&backup, &new_key, 4);
+			assert_ok!(AuraIdentity::initiate_backup_recovery(RuntimeOrigin::signed(4), 1, new_key, signature.clone()));
+			
+			// Пока идет восстановление, резервный ключ заморожен
+			assert_noop!(
+				AuraIdentity::remove_backup_key(RuntimeOrigin::signed(1)),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			
+			// Владелец с действующим ключом отменяет запрос
+			assert_ok!(AuraIdentity::cancel_recovery(RuntimeOrigin::signed(1)));
+			assert!(AuraIdentity::get_active_recovery(1).is_none());
+			assert!(!BackupApprovals::<Test>::contains_key(1));
+			
+			// Использованную подпись нельзя повторить
+			assert_noop!(
+				AuraIdentity::initiate_backup_recovery(RuntimeOrigin::signed(4), 1, new_key, signature),
+				Error::<Test>::InvalidBackupSignature
+			);
+			
+			assert_ok!(AuraIdentity::remove_backup_key(RuntimeOrigin::signed(1)));
+			assert_eq!(Balances::reserved_balance(1), reserved);
+			assert_noop!(
+				AuraIdentity::remove_backup_key(RuntimeOrigin::signed(1)),
+				Error::<Test>::BackupKeyNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn test_backup_key_requires_alternative_policy() {
+		new_test_ext().execute_with(|| {
+			setup_backup_key();
+			for account in [2, 3, 5, 6] {
+				create_aura_id_for_account(account);
+			}
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3, 5, 6], None));
+			
+			// Корень `All`: резервный ключ обошел бы обязательное участие организации
+			assert_noop!(
+				AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(policy_nodes())),
+				Error::<Test>::BackupKeyConflictsWithPolicy
+			);
+			
+			// «Резервный ключ ИЛИ политика» задается корнем `Any`
+			let mut alternatives = policy_nodes();
+			alternatives.push(PolicyNode::Any(vec![5].try_into().unwrap()));
+			assert_ok!(AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(alternatives)));
+			
+			// Без резервного ключа допустима любая политика, но ключ к ней не добавить
+			assert_ok!(AuraIdentity::remove_backup_key(RuntimeOrigin::signed(1)));
+			assert_ok!(AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(policy_nodes())));
+			let backup = key_pair(100);
+			assert_noop!(
+				AuraIdentity::set_backup_key(
+					RuntimeOrigin::signed(1),
+					AuraPublicKey::from(backup.public()),
+					key_proof(&backup, 1),
+					Some(500),
+				),
+				Error::<Test>::BackupKeyConflictsWithPolicy
+			);
+		});
+	}
+
+	#[test]
+	fn test_recovery_expires() {
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(3), 1));
+			let request = AuraIdentity::get_active_recovery(1).unwrap();
+			let expires_at = AuraIdentity::recovery_expires_at(&request);
+			assert_eq!(expires_at, 1 + (TestRecoveryDelay::get() + TestRecoveryLifetime::get()) as u64);
+			
+			// До истечения новый запрос невозможен, а удалить действующий нельзя
+			System::set_block_number(expires_at - 1);
+			assert_noop!(
+				AuraIdentity::initiate_recovery(RuntimeOrigin::signed(5), 1, AuraPublicKey::Sr25519([8u8; 32])),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			assert_noop!(
+				AuraIdentity::remove_expired_recovery(RuntimeOrigin::signed(5), 1),
+				Error::<Test>::RecoveryNotExpired
+			);
+			
+			// Истекший запрос нельзя выполнить
+			System::set_block_number(expires_at);
+			assert_noop!(
+				AuraIdentity::execute_recovery(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::RecoveryRequestExpired
+			);
+			assert!(!AuraIdentity::recovery_status(&1).unwrap().executable);
+			
+			// Новый инициатор заменяет истекший запрос, залог прежнего возвращен
+			assert_ok!(AuraIdentity::initiate_recovery(
+				RuntimeOrigin::signed(5),
+				1,
+				AuraPublicKey::Sr25519([8u8; 32])
+			));
+			System::assert_has_event(
+				Event::RecoveryExpired { lost_account: 1, requesting_account: 4, released: 50 }.into()
+			);
+			assert_eq!(Balances::reserved_balance(4), 0);
+			assert_eq!(Balances::free_balance(4), 1_000);
+			assert!(AuraIdentity::get_recovery_vouch(1, 2).is_none());
+			let request = AuraIdentity::get_active_recovery(1).unwrap();
+			assert_eq!(request.requesting_account, 5);
+			assert_eq!(request.submitted_shares, 0);
+			
+			// Истекший запрос может удалить любой аккаунт
+			System::set_block_number(AuraIdentity::recovery_expires_at(&request));
+			assert_ok!(AuraIdentity::remove_expired_recovery(RuntimeOrigin::signed(9), 1));
+			assert!(AuraIdentity::get_active_recovery(1).is_none());
+			assert_eq!(Balances::reserved_balance(5), 0);
+		});
+	}
+
+	#[test]
+	fn test_on_idle_removes_expired_recoveries() {
+		use frame_support::{traits::Hooks, weights::Weight};
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			let request = AuraIdentity::get_active_recovery(1).unwrap();
+			let expires_at = AuraIdentity::recovery_expires_at(&request);
+			
+			// Пока запрос действует, он остается
+			System::set_block_number(expires_at - 1);
+			AuraIdentity::on_idle(expires_at - 1, Weight::MAX);
+			assert!(AuraIdentity::get_active_recovery(1).is_some());
+			
+			// Без свободного веса очистка не выполняется
+			System::set_block_number(expires_at);
+			assert_eq!(AuraIdentity::on_idle(expires_at, Weight::zero()), Weight::zero());
+			assert!(AuraIdentity::get_active_recovery(1).is_some());
+			
+			AuraIdentity::on_idle(expires_at, Weight::MAX);
+			assert!(AuraIdentity::get_active_recovery(1).is_none());
+			assert_eq!(Balances::reserved_balance(4), 0);
+			System::assert_last_event(
+				Event::RecoveryExpired { lost_account: 1, requesting_account: 4, released: 50 }.into()
+			);
+		});
 	}
 
-	impl Config for Test {
-		type RuntimeEvent = RuntimeEvent;
-		type Currency = Balances;
-		type MaxTrustees = TestMaxTrustees;
-		type RecoveryDeposit = TestRecoveryDeposit;
+	#[test]
+	fn test_expired_recovery_releases_bond() {
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			let initiator_free = Balances::free_balance(4);
+			let owner_free = Balances::free_balance(1);
+			
+			// Истечение запроса без подтверждений не списывает залог: повторный
+			// запрос резервирует его заново
+			for _ in 1..=3 {
+				let request = AuraIdentity::get_active_recovery(1).unwrap();
+				System::set_block_number(AuraIdentity::recovery_expires_at(&request));
+				assert_ok!(AuraIdentity::initiate_recovery(
+					RuntimeOrigin::signed(4),
+					1,
+					AuraPublicKey::Sr25519([9u8; 32])
+				));
+				System::assert_has_event(
+					Event::RecoveryExpired { lost_account: 1, requesting_account: 4, released: 50 }.into()
+				);
+				assert_eq!(Balances::reserved_balance(4), 50);
+				assert_eq!(Balances::free_balance(4), initiator_free);
+				assert_eq!(Balances::free_balance(1), owner_free);
+			}
+			
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			let request = AuraIdentity::get_active_recovery(1).unwrap();
+			System::set_block_number(AuraIdentity::recovery_expires_at(&request));
+			assert_ok!(AuraIdentity::remove_expired_recovery(RuntimeOrigin::signed(9), 1));
+			System::assert_last_event(
+				Event::RecoveryExpired { lost_account: 1, requesting_account: 4, released: 50 }.into()
+			);
+			assert_eq!(Balances::reserved_balance(4), 0);
+			assert_eq!(Balances::free_balance(4), initiator_free + 50);
+		});
 	}
 
-	// СУЩЕСТВУЮЩИЕ ТЕСТЫ
 	#[test]
-	fn test_did_generation() {
-		let public_key = [1u8; 32];
-		let did = Pallet::<Test>::generate_did(&public_key);
-
-		assert_eq!(did.len(), 32);
-		assert_ne!(did, public_key);
+	fn test_trustee_confirmation() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=4 {
+				create_aura_id_for_account(account);
+			}
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3, 4], None));
+			
+			let mut unconfirmed = AuraIdentity::unconfirmed_trustees(&1);
+			unconfirmed.sort();
+			assert_eq!(unconfirmed, vec![2, 3, 4]);
+			
+			assert_ok!(AuraIdentity::accept_trusteeship(RuntimeOrigin::signed(2), 1));
+			assert_noop!(
+				AuraIdentity::accept_trusteeship(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::TrusteeAlreadyConfirmed
+			);
+			assert!(AuraIdentity::get_trustee_share(1, 2).unwrap().confirmed);
+			
+			// Отказ удаляет контакт из набора
+			assert_ok!(AuraIdentity::decline_trusteeship(RuntimeOrigin::signed(4), 1));
+			assert!(AuraIdentity::get_trustee_share(1, 4).is_none());
+			assert_eq!(AuraIdentity::get_recovery_config(1).unwrap().total_trustees, 2);
+			assert_eq!(AuraIdentity::unconfirmed_trustees(&1), vec![3]);
+			
+			// Неподтвержденный контакт не может поддержать восстановление
+			assert_ok!(AuraIdentity::initiate_recovery(RuntimeOrigin::signed(4), 1, AuraPublicKey::Sr25519([9u8; 32])));
+			assert_noop!(
+				AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::TrusteeNotConfirmed
+			);
+		});
 	}
 
 	#[test]
-	fn test_create_aura_id() {
+	fn test_decline_trusteeship_limits() {
 		new_test_ext().execute_with(|| {
-			let account_id = 1;
-			let public_key = [2u8; 32];
-			let recovery_config = vec![1, 2, 3];
+			setup_active_recovery();
+			create_aura_id_for_account(5);
+			
+			// Пока идет восстановление, контакт не может отказаться и сорвать запрос
+			assert_noop!(
+				AuraIdentity::decline_trusteeship(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			
+			// Контактов ровно столько, сколько требует порог
+			let request = AuraIdentity::get_active_recovery(1).unwrap();
+			System::set_block_number(AuraIdentity::recovery_expires_at(&request));
+			assert_noop!(
+				AuraIdentity::decline_trusteeship(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::TooFewTrustees
+			);
+			
+			// После добавления контакта отказ возможен, порог не превышает число контактов
+			assert_ok!(AuraIdentity::add_trustee(RuntimeOrigin::signed(1), 5));
+			assert_ok!(AuraIdentity::decline_trusteeship(RuntimeOrigin::signed(2), 1));
+			let config = AuraIdentity::get_recovery_config(1).unwrap();
+			assert_eq!((config.threshold, config.total_trustees), (2, 2));
+		});
+	}
 
-			assert_ok!(AuraIdentity::create_aura_id(
-				RuntimeOrigin::signed(account_id),
-				public_key,
-				recovery_config.clone()
-			));
+	#[test]
+	fn test_custom_recovery_delay() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=3 {
+				create_aura_id_for_account(account);
+			}
+			
+			// Период вне допустимых границ
+			assert_noop!(
+				AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], Some(5)),
+				Error::<Test>::InvalidRecoveryDelay
+			);
+			assert_noop!(
+				AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], Some(5_000)),
+				Error::<Test>::InvalidRecoveryDelay
+			);
+			
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], Some(500)));
+			assert_eq!(AuraIdentity::get_recovery_config(1).unwrap().delay_period, 500);
+		});
+	}
 
-			let record = AuraIdentity::get_aura_id(account_id).unwrap();
-			assert_eq!(record.public_key, public_key);
-			let expected_bounded: BoundedVec<u8, ConstU32<1024>> = recovery_config.try_into().unwrap();
-			assert_eq!(record.recovery_config, expected_bounded);
+	#[test]
+	fn test_update_recovery_delay_is_timelocked() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=3 {
+				create_aura_id_for_account(account);
+			}
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], None));
+			
+			// Увеличение применяется сразу
+			assert_ok!(AuraIdentity::update_recovery_config(RuntimeOrigin::signed(1), 200));
+			assert_eq!(AuraIdentity::get_recovery_config(1).unwrap().delay_period, 200);
+			
+			// Уменьшение откладывается на текущий период ожидания
+			assert_ok!(AuraIdentity::update_recovery_config(RuntimeOrigin::signed(1), 20));
+			assert_eq!(AuraIdentity::get_recovery_config(1).unwrap().delay_period, 200);
+			assert_eq!(AuraIdentity::get_pending_delay_update(1).unwrap().apply_at, 201);
+			
+			assert_noop!(
+				AuraIdentity::apply_recovery_config_update(RuntimeOrigin::signed(1)),
+				Error::<Test>::DelayPeriodNotPassed
+			);
+			
+			System::set_block_number(201);
+			assert_ok!(AuraIdentity::apply_recovery_config_update(RuntimeOrigin::signed(1)));
+			assert_eq!(AuraIdentity::get_recovery_config(1).unwrap().delay_period, 20);
+			assert!(AuraIdentity::get_pending_delay_update(1).is_none());
 		});
 	}
 
 	#[test]
-	fn test_duplicate_aura_id() {
+	fn test_recovery_status() {
 		new_test_ext().execute_with(|| {
-			let account_id = 1;
-			let public_key = [3u8; 32];
-			let recovery_config = vec![1, 2, 3];
+			setup_active_recovery();
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
 
-			assert_ok!(AuraIdentity::create_aura_id(
-				RuntimeOrigin::signed(account_id),
-				public_key,
-				recovery_config.clone()
-			));
+			let mut trustees = AuraIdentity::trustees(&1);
+			trustees.sort_by_key(|trustee| trustee.account);
+			assert_eq!(trustees, vec![
+				TrusteeInfo { account: 2, confirmed: true, vouched: true },
+				TrusteeInfo { account: 3, confirmed: true, vouched: false },
+			]);
+
+			let status = AuraIdentity::recovery_status(&1).unwrap();
+			assert_eq!(status.vouched, vec![2]);
+			assert_eq!(status.threshold, 2);
+			assert!(!status.executable);
 
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(3), 1));
+			System::set_block_number(1 + TestRecoveryDelay::get() as u64);
+			assert!(AuraIdentity::recovery_status(&1).unwrap().executable);
+			assert!(AuraIdentity::recovery_status(&2).is_none());
+		});
+	}
+
+	#[test]
+	fn test_remove_recovery() {
+		new_test_ext().execute_with(|| {
+			setup_active_recovery();
+			assert_eq!(Balances::reserved_balance(1), 100);
+			
+			// Пока идет восстановление, удалять конфигурацию нельзя
 			assert_noop!(
-				AuraIdentity::create_aura_id(
-					RuntimeOrigin::signed(account_id),
-					public_key,
-					recovery_config
-				),
-				Error::<Test>::AuraIdAlreadyExists
+				AuraIdentity::remove_recovery(RuntimeOrigin::signed(1)),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			
+			assert_ok!(AuraIdentity::cancel_recovery(RuntimeOrigin::signed(1)));
+			assert_ok!(AuraIdentity::remove_recovery(RuntimeOrigin::signed(1)));
+			
+			assert!(AuraIdentity::get_recovery_config(1).is_none());
+			assert!(AuraIdentity::get_recovery_deposit(1).is_none());
+			assert_eq!(TrusteeShares::<Test>::iter_prefix(1).count(), 0);
+			assert_eq!(Balances::reserved_balance(1), 0);
+			System::assert_last_event(Event::RecoveryRemoved { account: 1, deposit: 100 }.into());
+			
+			// Теперь recovery можно настроить заново
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], None));
+			
+			assert_noop!(
+				AuraIdentity::remove_recovery(RuntimeOrigin::signed(5)),
+				Error::<Test>::RecoveryNotConfigured
 			);
 		});
 	}
 
-	// НОВЫЕ ТЕСТЫ ДЛЯ SOCIAL RECOVERY
+	// ТЕСТЫ ДЕАКТИВАЦИИ
+
 	#[test]
-	fn test_setup_recovery() {
+	fn test_deactivate_aura_id() {
 		new_test_ext().execute_with(|| {
-			// Создаем аккаунты
-			let alice = 1;
-			let bob = 2;
-			let charlie = 3;
+			setup_active_recovery();
+			assert_ok!(AuraIdentity::add_service(
+				RuntimeOrigin::signed(1),
+				b"web".to_vec(),
+				b"LinkedDomains".to_vec(),
+				b"https://example.com".to_vec(),
+			));
+			let did = AuraIdentity::get_aura_id(1).unwrap().did;
 			
-			// Создаем Aura ID для всех участников
-			create_aura_id_for_account(alice);
-			create_aura_id_for_account(bob);
-			create_aura_id_for_account(charlie);
+			assert_ok!(AuraIdentity::deactivate_aura_id(RuntimeOrigin::signed(1)));
 			
-			// Настраиваем recovery систему для Alice
-			assert_ok!(AuraIdentity::setup_recovery(
-				RuntimeOrigin::signed(alice),
-				2,
-				vec![bob, charlie]
-			));
+			assert!(AuraIdentity::get_aura_id(1).is_none());
+			assert!(AuraIdentity::is_deactivated(&did));
+			let tombstone = AuraIdentity::tombstone(did).unwrap();
+			assert_eq!(tombstone.account, 1);
+			// История версий документа сохраняется: сервис и деактивация
+			assert_eq!(tombstone.version, DocumentVersion { version_id: 2, updated: 1 });
+			assert_eq!(AuraIdentity::document_version(1), DocumentVersion::default());
+			// DID нельзя занять снова, но он больше не указывает на аккаунт
+			assert_eq!(DidIndex::<Test>::get(did), Some(1));
+			assert_eq!(AuraIdentity::account_by_did(&did), None);
+			assert_eq!(AuraIdentity::did_document(&did), None);
 			
-			// Проверяем, что конфигурация создана
-			assert!(AuraIdentity::get_recovery_config(alice).is_some());
+			// Recovery, документ и все депозиты удалены, залог инициатора возвращен
+			assert!(AuraIdentity::get_recovery_config(1).is_none());
+			assert!(AuraIdentity::get_active_recovery(1).is_none());
+			assert_eq!(TrusteeShares::<Test>::iter_prefix(1).count(), 0);
+			assert_eq!(Services::<Test>::iter_prefix(1).count(), 0);
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Balances::reserved_balance(4), 0);
+			System::assert_last_event(Event::AuraIdDeactivated { account: 1, did, forced: false }.into());
 			
-			let config = AuraIdentity::get_recovery_config(alice).unwrap();
-			assert_eq!(config.threshold, 2);
-			assert_eq!(config.total_trustees, 2);
-			assert!(config.active);
+			// Деактивированный DID нельзя указать контроллером
+			assert_noop!(
+				AuraIdentity::add_controller(RuntimeOrigin::signed(2), did),
+				Error::<Test>::DidDeactivated
+			);
+			
+			assert_noop!(
+				AuraIdentity::deactivate_aura_id(RuntimeOrigin::signed(1)),
+				Error::<Test>::AuraIdNotFound
+			);
+			
+			// Аккаунт может зарегистрировать новый Aura ID с новым DID
+			create_aura_id_for_account(1);
+			assert_ne!(AuraIdentity::get_aura_id(1).unwrap().did, did);
+			assert_eq!(AuraIdentity::did_document(&did), None);
 		});
 	}
 
 	#[test]
-	fn test_add_remove_trustee() {
+	fn test_deactivated_trustee_is_removed() {
 		new_test_ext().execute_with(|| {
-			let alice = 1;
-			let bob = 2;
-			let charlie = 3;
-			let dave = 4;
+			setup_active_recovery();
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+			assert_eq!(AuraIdentity::get_active_recovery(1).unwrap().submitted_shares, 1);
 			
-			create_aura_id_for_account(alice);
-			create_aura_id_for_account(bob);
-			create_aura_id_for_account(charlie);
-			create_aura_id_for_account(dave);
+			assert_ok!(AuraIdentity::deactivate_aura_id(RuntimeOrigin::signed(2)));
 			
-			// Сначала настраиваем базовую систему
-			assert_ok!(AuraIdentity::setup_recovery(
-				RuntimeOrigin::signed(alice),
-				2,
-				vec![bob, charlie]
-			));
+			// Контакт убран у владельца вместе с подтверждением, порог не снижен
+			assert!(!TrusteeShares::<Test>::contains_key(1, 2));
+			assert_eq!(Trusteeships::<Test>::iter_prefix(2).count(), 0);
+			let config = AuraIdentity::get_recovery_config(1).unwrap();
+			assert_eq!((config.total_trustees, config.threshold), (1, 2));
+			assert!(AuraIdentity::get_recovery_vouch(1, 2).is_none());
+			assert_eq!(AuraIdentity::get_active_recovery(1).unwrap().submitted_shares, 0);
+			System::assert_has_event(Event::TrusteeRemoved { account: 1, trustee: 2 }.into());
+			assert_noop!(
+				AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::AuraIdNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn test_deactivated_trustee_drops_policy() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=6 {
+				create_aura_id_for_account(account);
+			}
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3, 5, 6], None));
+			assert_ok!(AuraIdentity::set_recovery_policy(RuntimeOrigin::signed(1), Some(policy_nodes())));
 			
-			// Добавляем нового доверенного контакта
-			assert_ok!(AuraIdentity::add_trustee(
-				RuntimeOrigin::signed(alice),
-				dave
-			));
+			// Лист политики с контактом 6 больше нельзя выполнить
+			assert_ok!(AuraIdentity::force_deactivate_aura_id(RuntimeOrigin::root(), 6));
+			assert!(AuraIdentity::recovery_policy(1).is_none());
+			assert_eq!(AuraIdentity::get_recovery_config(1).unwrap().total_trustees, 3);
+			System::assert_has_event(Event::RecoveryPolicyRemoved { account: 1 }.into());
+		});
+	}
+
+	#[test]
+	fn test_trusteeships_limit() {
+		new_test_ext().execute_with(|| {
+			for account in 1..=6 {
+				create_aura_id_for_account(account);
+			}
+			for owner in [1, 4, 5] {
+				assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(owner), 2, vec![2, 3], None));
+			}
+			assert_noop!(
+				AuraIdentity::setup_recovery(RuntimeOrigin::signed(6), 2, vec![2, 3], None),
+				Error::<Test>::TooManyTrusteeships
+			);
 			
-			// Проверяем, что контакт добавлен
-			assert!(AuraIdentity::get_trustee_share(alice, dave).is_some());
+			// Удаление настройки освобождает место
+			assert_ok!(AuraIdentity::remove_recovery(RuntimeOrigin::signed(4)));
+			assert!(!Trusteeships::<Test>::contains_key(2, 4));
+			assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(6), 2, vec![2, 3], None));
+		});
+	}
+
+	#[test]
+	fn test_force_deactivate_aura_id() {
+		new_test_ext().execute_with(|| {
+			create_aura_id_for_account(1);
+			let did = AuraIdentity::get_aura_id(1).unwrap().did;
 			
-			let config = AuraIdentity::get_recovery_config(alice).unwrap();
-			assert_eq!(config.total_trustees, 3);
+			assert_noop!(
+				AuraIdentity::force_deactivate_aura_id(RuntimeOrigin::signed(2), 1),
+				DispatchError::BadOrigin
+			);
 			
-			// Удаляем доверенный контакт
-			assert_ok!(AuraIdentity::remove_trustee(
-				RuntimeOrigin::signed(alice),
-				dave
+			assert_ok!(AuraIdentity::force_deactivate_aura_id(RuntimeOrigin::root(), 1));
+			assert!(AuraIdentity::tombstone(did).unwrap().forced);
+			System::assert_last_event(Event::AuraIdDeactivated { account: 1, did, forced: true }.into());
+		});
+	}
+
+	// ТЕСТЫ GENESIS
+
+	// Хранилище после генезиса с Aura ID для 1..=3 и восстановлением Alice
+	fn genesis_storage(recovery_setups: Vec<(u64, u8, Vec<u64>, Option<u32>)>) -> sp_runtime::Storage {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: (1..=3).map(|account| (account, 1_000)).collect(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		GenesisConfig::<Test> {
+			identities: (1..=3).map(|account| (account, [account as u8; 32])).collect(),
+			recovery_setups,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		t
+	}
+
+	#[test]
+	fn test_genesis_identities_and_recovery() {
+		let mut ext: sp_io::TestExternalities = genesis_storage(vec![(1, 2, vec![2, 3], None)]).into();
+		ext.execute_with(|| {
+			let record = AuraIdentity::get_aura_id(1).unwrap();
+			assert_eq!(record.public_key, AuraPublicKey::Sr25519([1u8; 32]));
+			assert_eq!(record.did, Pallet::<Test>::generate_did(&1, &record.public_key, 0));
+			assert_eq!(DidIndex::<Test>::get(record.did), Some(1));
+			assert_eq!(AuraIdentity::key_proof_nonce(1), 1);
+
+			let config = AuraIdentity::get_recovery_config(1).unwrap();
+			assert_eq!(config.threshold, 2);
+			assert_eq!(config.total_trustees, 2);
+			assert_eq!(config.delay_period, TestRecoveryDelay::get());
+			assert_eq!(Balances::reserved_balance(1), 100);
+			assert!(AuraIdentity::get_trustee_share(1, 2).unwrap().confirmed);
+
+			// Восстановление из генезиса работает как обычное
+			System::set_block_number(1);
+			assert_ok!(AuraIdentity::initiate_recovery(RuntimeOrigin::signed(3), 1, AuraPublicKey::Sr25519([9u8; 32])));
+			assert_ok!(AuraIdentity::submit_recovery_share(RuntimeOrigin::signed(2), 1));
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid genesis recovery threshold")]
+	fn test_genesis_rejects_invalid_threshold() {
+		genesis_storage(vec![(1, 1, vec![2, 3], None)]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Genesis trustee without Aura ID")]
+	fn test_genesis_rejects_unknown_trustee() {
+		genesis_storage(vec![(1, 2, vec![2, 7], None)]);
+	}
+
+	// ТЕСТЫ МИГРАЦИЙ
+
+	// Хранилище в формате версии 0: Aura ID аккаунтов 1..=3, у аккаунта 1
+	// настроено восстановление с контактами 2, 3 и идет запрос без залога
+	fn insert_legacy_identities() {
+		use frame_support::traits::StorageVersion;
+		for account in 1..=3u64 {
+			let did = [account as u8; 32];
+			migrations::v0::AuraIdentities::<Test>::insert(account, migrations::v0::OldAuraIdRecord {
+				did,
+				public_key: [account as u8; 32],
+				recovery_config: BoundedVec::default(),
+				created: 0,
+			});
+			DidIndex::<Test>::insert(did, account);
+		}
+		migrations::v0::RecoveryConfigs::<Test>::insert(1, migrations::v0::OldRecoveryConfig {
+			threshold: 2,
+			total_trustees: 2,
+			delay_period: 100,
+			active: true,
+			deposit: 10,
+		});
+		for trustee in 2..=3u64 {
+			migrations::v0::TrusteeShares::<Test>::insert(1, trustee, migrations::v0::OldTrusteeShare {
+				trustee_account: trustee,
+				share: BoundedVec::truncate_from(vec![trustee as u8; 4]),
+				confirmed: true,
+			});
+		}
+		migrations::v0::ActiveRecoveries::<Test>::insert(1, migrations::v0::OldRecoveryRequest {
+			requesting_account: 4,
+			new_public_key: [9u8; 32],
+			submitted_shares: 1,
+			execute_at: 101,
+			completed: false,
+		});
+		StorageVersion::new(0).put::<AuraIdentity>();
+	}
+
+	// Все хранилища читаются в текущем формате после миграции с версии 0
+	fn assert_migrated_from_v0() {
+		for account in 1..=3u64 {
+			let record = AuraIdentity::get_aura_id(account).unwrap();
+			// Блок регистрации неизвестен, а не равен блоку обновления
+			assert_eq!(record.created, None);
+			assert_eq!(record.public_key, AuraPublicKey::Legacy([account as u8; 32]));
+		}
+		// Без блока регистрации ключ без истории считается действующим с генезиса
+		assert_eq!(AuraIdentity::key_at(&2, 0), Some(AuraPublicKey::Legacy([2u8; 32])));
+		assert!(AuraIdentity::key_history(1).is_empty());
+
+		let request = AuraIdentity::get_active_recovery(1).unwrap();
+		assert_eq!(request.requesting_account, 4);
+		// Залог в версии 0 не резервировался
+		assert_eq!(request.bond, 0);
+		assert_eq!(request.new_public_key, AuraPublicKey::Legacy([9u8; 32]));
+		assert_eq!(request.submitted_shares, 1);
+		assert_eq!(request.execute_at, 101);
+
+		let config = AuraIdentity::get_recovery_config(1).unwrap();
+		assert_eq!((config.threshold, config.total_trustees, config.delay_period), (2, 2, 100));
+		assert_eq!(config.deposit, 10);
+		let share = AuraIdentity::get_trustee_share(1, 3).unwrap();
+		assert_eq!(share.trustee_account, 3);
+		assert_eq!(share.share.into_inner(), vec![3u8; 4]);
+		assert!(share.confirmed);
+		assert_eq!(Trusteeships::<Test>::iter_keys().count(), 2);
+		assert!(Trusteeships::<Test>::contains_key(2, 1) && Trusteeships::<Test>::contains_key(3, 1));
+	}
+
+	#[test]
+	fn test_migrate_to_v2() {
+		use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+		new_test_ext().execute_with(|| {
+			insert_legacy_identities();
+			System::set_block_number(42);
+
+			migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(AuraIdentity::on_chain_storage_version(), STORAGE_VERSION);
+			assert_migrated_from_v0();
+
+			// Повторный запуск ничего не меняет
+			let records: Vec<_> = AuraIdentities::<Test>::iter().collect();
+			let requests: Vec<_> = ActiveRecoveries::<Test>::iter().collect();
+			migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+			assert_eq!(AuraIdentities::<Test>::iter().collect::<Vec<_>>(), records);
+			assert_eq!(ActiveRecoveries::<Test>::iter().collect::<Vec<_>>(), requests);
+		});
+	}
+
+	#[test]
+	fn test_lazy_migrate_to_v2() {
+		use frame_support::traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade};
+		new_test_ext().execute_with(|| {
+			insert_legacy_identities();
+			System::set_block_number(42);
+
+			migrations::v2::LazyMigrateToV2::<Test>::on_runtime_upgrade();
+			assert!(AuraIdentity::migration_ongoing());
+			assert_eq!(AuraIdentity::on_chain_storage_version(), 0);
+
+			// Без свободного веса записи не переносятся
+			AuraIdentity::on_idle(42, frame_support::weights::Weight::zero());
+			assert!(AuraIdentity::migration_ongoing());
+			// Шаг ограничен и размером доказательства, а не только временем
+			AuraIdentity::on_idle(42, frame_support::weights::Weight::from_parts(u64::MAX, 0));
+			assert!(AuraIdentity::migration_ongoing());
+			assert_eq!(AuraIdentity::on_chain_storage_version(), 0);
+
+			// Миграция не запускается повторно поверх идущей
+			migrations::v2::LazyMigrateToV2::<Test>::on_runtime_upgrade();
+
+			// Аккаунты перенесены, курсор переходит к индексу контактов
+			AuraIdentity::on_idle(42, frame_support::weights::Weight::MAX);
+			assert!(AuraIdentity::migration_ongoing());
+			assert_eq!(AuraIdentity::on_chain_storage_version(), 0);
+			assert_eq!(Trusteeships::<Test>::iter_keys().count(), 0);
+
+			AuraIdentity::on_idle(42, frame_support::weights::Weight::MAX);
+			assert!(!AuraIdentity::migration_ongoing());
+			assert_eq!(AuraIdentity::on_chain_storage_version(), STORAGE_VERSION);
+			assert_migrated_from_v0();
+		});
+	}
+
+	#[test]
+	fn test_migrate_ed25519_key_to_v2() {
+		use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+		new_test_ext().execute_with(|| {
+			// Запись версии 0 с ключом ed25519: алгоритм в хранилище не записан
+			let ed = sp_core::ed25519::Pair::from_seed(&[5u8; 32]);
+			migrations::v0::AuraIdentities::<Test>::insert(5, migrations::v0::OldAuraIdRecord {
+				did: [5u8; 32],
+				public_key: ed.public().0,
+				recovery_config: BoundedVec::default(),
+				created: 0,
+			});
+			DidIndex::<Test>::insert([5u8; 32], 5);
+			StorageVersion::new(0).put::<AuraIdentity>();
+
+			migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+			// Ключ не выдается за sr25519 и не попадает в DID документ
+			let legacy = AuraPublicKey::Legacy(ed.public().0);
+			assert_eq!(AuraIdentity::get_aura_id(5).unwrap().public_key, legacy);
+			assert_eq!(legacy.key_type(), None);
+			assert!(!legacy.verify(b"message", &ed.sign(b"message").into()));
+
+			// Ключ версии 0 нельзя назначить ни восстановлением, ни сменой ключа
+			assert_noop!(
+				AuraIdentity::initiate_recovery(RuntimeOrigin::signed(6), 5, AuraPublicKey::Legacy([1u8; 32])),
+				Error::<Test>::LegacyKey
+			);
+			let nonce = AuraIdentity::key_proof_nonce(5);
+			let other = AuraPublicKey::Legacy([1u8; 32]);
+			assert_noop!(
+				AuraIdentity::rotate_key(
+					RuntimeOrigin::signed(5),
+					other,
+					ed.sign(&AuraIdentity::key_proof_payload(&5, nonce)).into(),
+					ed.sign(&AuraIdentity::key_rotation_payload(&5, &other, nonce)).into()
+				),
+				Error::<Test>::LegacyKey
+			);
+
+			// Замену разрешает подпись ed25519 старым ключом
+			let new_pair = key_pair(50);
+			let new_key = AuraPublicKey::from(new_pair.public());
+			assert_noop!(
+				AuraIdentity::rotate_key(
+					RuntimeOrigin::signed(5),
+					new_key,
+					key_proof(&new_pair, 5),
+					rotation_authorization(&key_pair(5), 5, &new_key)
+				),
+				Error::<Test>::InvalidRotationAuthorization
+			);
+			assert_ok!(AuraIdentity::rotate_key(
+				RuntimeOrigin::signed(5),
+				new_key,
+				key_proof(&new_pair, 5),
+				ed.sign(&AuraIdentity::key_rotation_payload(&5, &new_key, nonce)).into()
 			));
-			
-			// Проверяем, что контакт удален
-			assert!(AuraIdentity::get_trustee_share(alice, dave).is_none());
+			let record = AuraIdentity::get_aura_id(5).unwrap();
+			assert_eq!(record.did, [5u8; 32]);
+			assert_eq!(record.public_key, new_key);
+			assert_eq!(AuraIdentity::key_history(5).last().map(|key| key.public_key), Some(legacy));
 		});
 	}
 
+	// Детерминированная пара ключей для тестов
+	fn key_pair(seed: u8) -> sr25519::Pair {
+		sr25519::Pair::from_seed(&[seed; 32])
+	}
+
+	// Доказательство владения ключом для текущего nonce аккаунта
+	fn key_proof(pair: &sr25519::Pair, account: u64) -> AuraSignature {
+		let nonce = AuraIdentity::key_proof_nonce(account);
+		pair.sign(&AuraIdentity::key_proof_payload(&account, nonce)).into()
+	}
+
+	// Разрешение текущего ключа `pair` на смену ключа на `new_key`
+	fn rotation_authorization(pair: &sr25519::Pair, account: u64, new_key: &AuraPublicKey) -> AuraSignature {
+		let nonce = AuraIdentity::key_proof_nonce(account);
+		pair.sign(&AuraIdentity::key_rotation_payload(&account, new_key, nonce)).into()
+	}
+
 	// Вспомогательная функция для создания Aura ID
 	fn create_aura_id_for_account(account: u64) {
+		let pair = key_pair(account as u8);
 		let _ = AuraIdentity::create_aura_id(
 			RuntimeOrigin::signed(account),
-			[account as u8; 32],
+			AuraPublicKey::from(pair.public()),
 			vec![],
+			key_proof(&pair, account),
 		);
 	}
 
-	// Вспомогательная функция для тестов
-	fn new_test_ext() -> sp_io::TestExternalities {
-		let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
-		t.into()
+	// Вспомогательная функция: Alice (1) настраивает recovery с Bob (2) и Charlie (3),
+	// Dave (4) инициирует восстановление
+	fn setup_active_recovery() {
+		for account in 1..=4 {
+			create_aura_id_for_account(account);
+		}
+		assert_ok!(AuraIdentity::setup_recovery(RuntimeOrigin::signed(1), 2, vec![2, 3], None));
+		assert_ok!(AuraIdentity::accept_trusteeship(RuntimeOrigin::signed(2), 1));
+		assert_ok!(AuraIdentity::accept_trusteeship(RuntimeOrigin::signed(3), 1));
+		assert_ok!(AuraIdentity::initiate_recovery(RuntimeOrigin::signed(4), 1, AuraPublicKey::Sr25519([9u8; 32])));
+	}
+
+	// Вспомогательная функция для тестов (используется и в бенчмарках)
+	pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: (1..=10).map(|account| (account, 1_000)).collect(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		let mut ext: sp_io::TestExternalities = t.into();
+		ext.execute_with(|| System::set_block_number(1));
+		ext
 	}
 }
@@ -0,0 +1,416 @@
+//! Миграции хранилища паллета aura-identity
+//!
+//! Модуль `v0` описывает исходный формат хранилища, модуль `v2` - миграцию
+//! с него сразу на текущую версию. Миграция есть в двух вариантах: одноблочная
+//! (`MigrateToV2`) для небольших сетей и многоблочная (`LazyMigrateToV2`),
+//! которая в `on_runtime_upgrade` только запускает курсор `MigrationCursor`,
+//! а записи переносит порциями в `on_idle`. Пока курсор существует, runtime
+//! должен отклонять вызовы паллета.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::StoragePrefixedMap,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Zero;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Верхняя граница записей, переносимых за один блок многоблочной миграцией
+pub const MAX_MIGRATION_BATCH: u32 = 1_000;
+
+/// Шаг многоблочной миграции для версии хранилища в цепи
+pub fn migrate_step<T: Config>(remaining_weight: Weight) -> Weight {
+	if Pallet::<T>::on_chain_storage_version() == StorageVersion::new(0) {
+		v2::migrate_step::<T>(remaining_weight)
+	} else {
+		// Курсор остался от уже выполненной миграции
+		MigrationCursor::<T>::kill();
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
+
+/// Сколько записей помещается в `remaining_weight` после базового веса шага
+/// по обоим измерениям веса (не больше `MAX_MIGRATION_BATCH`)
+fn batch_limit(remaining_weight: Weight, base: Weight, per_record: Weight) -> usize {
+	let Some(available) = remaining_weight.checked_sub(&base) else {
+		return 0;
+	};
+	let by_ref_time = available.ref_time() / per_record.ref_time().max(1);
+	let by_proof_size = available.proof_size() / per_record.proof_size().max(1);
+	by_ref_time.min(by_proof_size).min(MAX_MIGRATION_BATCH as u64) as usize
+}
+
+/// Вес чтения и записи курсора и версии хранилища
+fn base_weight<T: Config>() -> Weight {
+	let cursor = BoundedVec::<u8, ConstU32<256>>::max_encoded_len() as u64;
+	T::DbWeight::get()
+		.reads_writes(1, 2)
+		.saturating_add(Weight::from_parts(0, cursor))
+}
+
+/// Размер доказательства для записи `value_len` байт в карте с ключом
+/// `Blake2_128Concat(AccountId)`
+fn entry_proof_size<T: Config>(value_len: usize) -> u64 {
+	(16 + T::AccountId::max_encoded_len() + value_len) as u64
+}
+
+/// Сохраняет курсор после `last`, если порция заполнена. Иначе записи
+/// закончились: ставит курсор на начало следующего этапа `next` либо, если
+/// этапов больше нет, ставит текущую версию хранилища и удаляет курсор.
+fn advance_cursor<T: Config>(last: Option<Vec<u8>>, migrated: usize, limit: usize, next: Option<Vec<u8>>) {
+	let cursor = match (last, next) {
+		(Some(key), _) if migrated == limit => Some(key),
+		(_, next) => next,
+	};
+	match cursor {
+		Some(key) => {
+			let cursor: BoundedVec<u8, ConstU32<256>> =
+				key.try_into().expect("migrated storage keys are far shorter than 256 bytes; qed");
+			MigrationCursor::<T>::put(cursor);
+		},
+		None => {
+			STORAGE_VERSION.put::<Pallet<T>>();
+			MigrationCursor::<T>::kill();
+			log::info!(target: LOG_TARGET, "lazy migration to {:?} completed", STORAGE_VERSION);
+		},
+	}
+}
+
+/// Исходный формат хранилища (версия 0)
+pub mod v0 {
+	use super::*;
+
+	/// `AuraIdRecord` версии 0
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct OldAuraIdRecord {
+		pub did: [u8; 32],
+		/// Ключ без алгоритма: sr25519, ed25519 или blake2_256 сжатого ключа ecdsa
+		pub public_key: [u8; 32],
+		pub recovery_config: BoundedVec<u8, ConstU32<1024>>,
+		/// Всегда `0`: время регистрации не сохранялось
+		pub created: u32,
+	}
+
+	/// `RecoveryConfig` версии 0. Кодировка совпадает с текущей.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct OldRecoveryConfig<Balance> {
+		pub threshold: u8,
+		pub total_trustees: u8,
+		pub delay_period: u32,
+		pub active: bool,
+		pub deposit: Balance,
+	}
+
+	/// `TrusteeShare` версии 0. Кодировка совпадает с текущей.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct OldTrusteeShare<AccountId> {
+		pub trustee_account: AccountId,
+		pub share: BoundedVec<u8, ConstU32<1024>>,
+		pub confirmed: bool,
+	}
+
+	/// `RecoveryRequest` версии 0: без залога инициатора, ключ без алгоритма
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct OldRecoveryRequest<AccountId> {
+		pub requesting_account: AccountId,
+		pub new_public_key: [u8; 32],
+		pub submitted_shares: u8,
+		pub execute_at: u32,
+		pub completed: bool,
+	}
+
+	#[frame_support::storage_alias]
+	pub type AuraIdentities<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, <T as frame_system::Config>::AccountId, OldAuraIdRecord>;
+
+	#[frame_support::storage_alias]
+	pub type RecoveryConfigs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		OldRecoveryConfig<BalanceOf<T>>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type TrusteeShares<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		OldTrusteeShare<<T as frame_system::Config>::AccountId>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type ActiveRecoveries<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		OldRecoveryRequest<<T as frame_system::Config>::AccountId>,
+	>;
+}
+
+/// Версия 2: переход с версии 0 сразу на текущий формат
+///
+/// Изменения по хранилищам:
+/// - `AuraIdentities`: ключ с алгоритмом (`AuraPublicKey`), `created` -
+///   `Option` блока регистрации вместо заглушки `0u32`. Блок регистрации
+///   старых записей неизвестен, поэтому `created` остается `None`.
+/// - `ActiveRecoveries`: добавлен залог инициатора `bond`. В версии 0 залог
+///   не резервировался, поэтому он равен нулю, и при завершении запроса
+///   ничего не разблокируется и не списывается.
+/// - `RecoveryConfigs`, `TrusteeShares`: кодировка не изменилась, записи
+///   не переписываются, а только проверяются в `post_upgrade`.
+/// - `Trusteeships`: обратный индекс строится по `TrusteeShares`. Лимит
+///   `MaxTrusteeships` для перенесенных записей не проверяется.
+/// - `KeyHistory`: появилась после версии 0 и пуста, переносить нечего.
+///
+/// Алгоритм старых ключей в хранилище не записан: это мог быть ключ sr25519,
+/// ключ ed25519 или blake2_256 сжатого ключа ecdsa. Поэтому старые ключи
+/// переносятся как `AuraPublicKey::Legacy`, а не выдаются за sr25519. Такой
+/// ключ не попадает в DID документ; владелец заменяет его через `rotate_key`,
+/// подписав смену старым ключом любого из трех алгоритмов, - DID при этом
+/// не меняется.
+pub mod v2 {
+	use super::*;
+	use v0::{OldAuraIdRecord, OldRecoveryRequest};
+
+	fn migrate_record<T: Config>(old: OldAuraIdRecord) -> AuraIdRecord<BlockNumberFor<T>> {
+		AuraIdRecord {
+			did: old.did,
+			public_key: AuraPublicKey::Legacy(old.public_key),
+			recovery_config: old.recovery_config,
+			created: None,
+		}
+	}
+
+	fn migrate_request<T: Config>(
+		old: OldRecoveryRequest<T::AccountId>,
+	) -> RecoveryRequest<T::AccountId, BalanceOf<T>, BlockNumberFor<T>> {
+		RecoveryRequest {
+			requesting_account: old.requesting_account,
+			bond: Zero::zero(),
+			new_public_key: AuraPublicKey::Legacy(old.new_public_key),
+			submitted_shares: old.submitted_shares,
+			execute_at: old.execute_at.into(),
+			completed: old.completed,
+		}
+	}
+
+	/// Переносит Aura ID аккаунта вместе с его активным восстановлением.
+	/// Запрос на восстановление существует только у аккаунтов с Aura ID.
+	fn migrate_account<T: Config>(account: &T::AccountId, old: OldAuraIdRecord) {
+		crate::AuraIdentities::<T>::insert(account, migrate_record::<T>(old));
+		if let Some(request) = v0::ActiveRecoveries::<T>::get(account) {
+			crate::ActiveRecoveries::<T>::insert(account, migrate_request::<T>(request));
+		}
+	}
+
+	/// Одноблочная миграция: все записи переносятся в `on_runtime_upgrade`
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				log::info!(target: LOG_TARGET, "v2 migration skipped: storage is not at version 0");
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut migrated = 0u64;
+			crate::AuraIdentities::<T>::translate::<OldAuraIdRecord, _>(|_, old| {
+				migrated += 1;
+				Some(migrate_record::<T>(old))
+			});
+			crate::ActiveRecoveries::<T>::translate::<OldRecoveryRequest<T::AccountId>, _>(|_, old| {
+				migrated += 1;
+				Some(migrate_request::<T>(old))
+			});
+			for (owner, trustee) in crate::TrusteeShares::<T>::iter_keys() {
+				migrated += 1;
+				Trusteeships::<T>::insert(trustee, owner, ());
+			}
+			STORAGE_VERSION.put::<Pallet<T>>();
+
+			log::info!(target: LOG_TARGET, "v2 migration: {} records migrated", migrated);
+			T::DbWeight::get().reads_writes(migrated + 1, migrated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			pre_state::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == STORAGE_VERSION, "v2: storage version not updated");
+			check_records::<T>(state)
+		}
+	}
+
+	/// Многоблочная миграция: `on_runtime_upgrade` запускает курсор, записи
+	/// переносятся в `on_idle` через `migrate_step`
+	pub struct LazyMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for LazyMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 || MigrationCursor::<T>::exists() {
+				return T::DbWeight::get().reads(2);
+			}
+
+			MigrationCursor::<T>::put(BoundedVec::default());
+			log::info!(target: LOG_TARGET, "v2 lazy migration started");
+			T::DbWeight::get().reads_writes(2, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			pre_state::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				MigrationCursor::<T>::exists() || Pallet::<T>::on_chain_storage_version() == STORAGE_VERSION,
+				"v2: lazy migration was not started"
+			);
+			Ok(())
+		}
+	}
+
+	/// Шаг многоблочной миграции. Сначала переносятся аккаунты с Aura ID,
+	/// затем строится индекс `Trusteeships`: курсор второго этапа - ключ в
+	/// `TrusteeShares`, что и отличает этапы.
+	pub fn migrate_step<T: Config>(remaining_weight: Weight) -> Weight {
+		let cursor = MigrationCursor::<T>::get().unwrap_or_default().into_inner();
+		if cursor.starts_with(&crate::TrusteeShares::<T>::final_prefix()) {
+			index_trustees_step::<T>(remaining_weight, cursor)
+		} else {
+			migrate_accounts_step::<T>(remaining_weight, cursor)
+		}
+	}
+
+	/// Переносит столько аккаунтов, сколько помещается в `remaining_weight`
+	/// (не больше `MAX_MIGRATION_BATCH`). Когда аккаунты закончились,
+	/// переводит курсор на начало `TrusteeShares`.
+	fn migrate_accounts_step<T: Config>(remaining_weight: Weight, cursor: Vec<u8>) -> Weight {
+		let base = base_weight::<T>();
+		// Aura ID и активное восстановление; новые записи не короче старых
+		let proof_size = entry_proof_size::<T>(AuraIdRecord::<BlockNumberFor<T>>::max_encoded_len())
+			.saturating_add(entry_proof_size::<T>(
+				RecoveryRequest::<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>::max_encoded_len(),
+			));
+		let per_record = T::DbWeight::get()
+			.reads_writes(2, 2)
+			.saturating_add(Weight::from_parts(0, proof_size));
+
+		let limit = batch_limit(remaining_weight, base, per_record);
+		if limit == 0 {
+			return Weight::zero();
+		}
+
+		let records: Vec<_> = if cursor.is_empty() {
+			v0::AuraIdentities::<T>::iter().take(limit).collect()
+		} else {
+			v0::AuraIdentities::<T>::iter_from(cursor).take(limit).collect()
+		};
+
+		let migrated = records.len();
+		let last = records.last().map(|(account, _)| crate::AuraIdentities::<T>::hashed_key_for(account));
+		for (account, old) in records {
+			migrate_account::<T>(&account, old);
+		}
+		let next = crate::TrusteeShares::<T>::final_prefix().to_vec();
+		advance_cursor::<T>(last, migrated, limit, Some(next));
+
+		base.saturating_add(per_record.saturating_mul(migrated as u64))
+	}
+
+	/// Добавляет в `Trusteeships` столько записей `TrusteeShares`, сколько
+	/// помещается в `remaining_weight`. Завершает миграцию, когда записи
+	/// закончились: удаляет курсор и ставит текущую версию хранилища.
+	fn index_trustees_step<T: Config>(remaining_weight: Weight, cursor: Vec<u8>) -> Weight {
+		let base = base_weight::<T>();
+		// Второй ключ карты тоже `Blake2_128Concat(AccountId)`
+		let key_len = 16 + T::AccountId::max_encoded_len();
+		let proof_size = entry_proof_size::<T>(key_len + TrusteeShare::<T::AccountId>::max_encoded_len())
+			.saturating_add(entry_proof_size::<T>(key_len));
+		let per_record = T::DbWeight::get()
+			.reads_writes(1, 1)
+			.saturating_add(Weight::from_parts(0, proof_size));
+
+		let limit = batch_limit(remaining_weight, base, per_record);
+		if limit == 0 {
+			return Weight::zero();
+		}
+
+		let keys: Vec<_> = crate::TrusteeShares::<T>::iter_keys_from(cursor).take(limit).collect();
+		let migrated = keys.len();
+		let last = keys.last().map(|(owner, trustee)| crate::TrusteeShares::<T>::hashed_key_for(owner, trustee));
+		for (owner, trustee) in keys {
+			Trusteeships::<T>::insert(trustee, owner, ());
+		}
+		advance_cursor::<T>(last, migrated, limit, None);
+
+		base.saturating_add(per_record.saturating_mul(migrated as u64))
+	}
+
+	/// Число записей каждого хранилища до миграции
+	#[cfg(feature = "try-runtime")]
+	fn pre_state<T: Config>() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let counts = (
+			v0::AuraIdentities::<T>::iter_keys().count() as u64,
+			v0::ActiveRecoveries::<T>::iter_keys().count() as u64,
+			v0::RecoveryConfigs::<T>::iter_keys().count() as u64,
+			v0::TrusteeShares::<T>::iter_keys().count() as u64,
+		);
+		Ok(counts.encode())
+	}
+
+	/// Проверки после миграции: все записи читаются в новом формате, их число
+	/// не изменилось, `DidIndex` указывает на владельцев Aura ID
+	#[cfg(feature = "try-runtime")]
+	fn check_records<T: Config>(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let (identities, recoveries, configs, shares) =
+			<(u64, u64, u64, u64)>::decode(&mut &state[..]).map_err(|_| "v2: invalid pre-upgrade state")?;
+
+		let mut migrated = 0u64;
+		for (account, record) in crate::AuraIdentities::<T>::iter() {
+			ensure!(record.created.is_none(), "v2: unknown creation block was filled in");
+			ensure!(
+				DidIndex::<T>::get(record.did).as_ref() == Some(&account),
+				"v2: DidIndex does not point to the Aura ID owner"
+			);
+			migrated += 1;
+		}
+		ensure!(migrated == identities, "v2: Aura ID count changed");
+
+		let mut migrated = 0u64;
+		for (account, request) in crate::ActiveRecoveries::<T>::iter() {
+			ensure!(crate::AuraIdentities::<T>::contains_key(&account), "v2: recovery without Aura ID");
+			ensure!(request.bond.is_zero(), "v2: recovery bond was not reserved in version 0");
+			migrated += 1;
+		}
+		ensure!(migrated == recoveries, "v2: recovery count changed");
+
+		ensure!(
+			crate::RecoveryConfigs::<T>::iter_values().count() as u64 == configs,
+			"v2: recovery config does not decode"
+		);
+		ensure!(
+			crate::TrusteeShares::<T>::iter_values().count() as u64 == shares,
+			"v2: trustee share does not decode"
+		);
+		for (owner, trustee) in crate::TrusteeShares::<T>::iter_keys() {
+			ensure!(Trusteeships::<T>::contains_key(&trustee, &owner), "v2: trusteeship is not indexed");
+		}
+		ensure!(
+			Trusteeships::<T>::iter_keys().count() as u64 == shares,
+			"v2: trusteeship without trustee share"
+		);
+		ensure!(crate::KeyHistory::<T>::iter_keys().next().is_none(), "v2: key history before migration");
+		Ok(())
+	}
+}
@@ -0,0 +1,135 @@
+//! Проверяемое разделение секрета (Feldman VSS) на кривой P-256
+//!
+//! Владелец делит секрет многочленом `f(x) = a_0 + a_1·x + ... + a_{t-1}·x^{t-1}`
+//! и публикует обязательства `C_j = a_j·G` (сжатые точки SEC1, 33 байта).
+//! Доверенный контакт с индексом `i` получает зашифрованный шар `s_i = f(i)`.
+//! Публичный шар `S_i = s_i·G = Σ C_j·i^j` вычисляется по обязательствам, поэтому
+//! контакт может проверить свой шар, не раскрывая его, а runtime - проверить
+//! шар, раскрытый владельцем в ответ на жалобу.
+//!
+//! Доказательство владения корректным шаром - подпись Шнорра над `S_i`:
+//! `R = k·G`, `c = H(context, S_i, R)`, `z = k + c·s_i`; проверка
+//! `z·G == R + c·S_i`, где `H` - blake2_256, приведенный по модулю порядка группы.
+
+use frame_support::pallet_prelude::*;
+use p256::{
+	elliptic_curve::{
+		ops::Reduce,
+		sec1::{FromEncodedPoint, ToEncodedPoint},
+		PrimeField,
+	},
+	AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256,
+};
+
+/// Обязательство на коэффициент многочлена - сжатая точка P-256
+pub type ShareCommitment = [u8; 33];
+
+/// Шар секрета в открытом виде - скаляр P-256 (big-endian)
+pub type ShareScalar = [u8; 32];
+
+/// Доказательство того, что контакт знает корректный шар
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ShareProof {
+	/// `R = k·G`
+	pub commitment: ShareCommitment,
+	/// `z = k + c·s_i`
+	pub response: ShareScalar,
+}
+
+pub(crate) fn decode_point(bytes: &ShareCommitment) -> Option<ProjectivePoint> {
+	let encoded = EncodedPoint::from_bytes(bytes).ok()?;
+	Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded)).map(ProjectivePoint::from)
+}
+
+pub(crate) fn decode_scalar(bytes: &ShareScalar) -> Option<Scalar> {
+	Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(bytes)))
+}
+
+fn encode_point(point: &ProjectivePoint) -> ShareCommitment {
+	point
+		.to_affine()
+		.to_encoded_point(true)
+		.as_bytes()
+		.try_into()
+		.unwrap_or([0u8; 33])
+}
+
+/// Корректны ли обязательства: каждое - точка кривой
+pub fn valid_commitments(commitments: &[ShareCommitment]) -> bool {
+	!commitments.is_empty() && commitments.iter().all(|commitment| decode_point(commitment).is_some())
+}
+
+/// Публичный шар `S_i = Σ C_j·i^j` (схема Горнера)
+pub fn public_share(commitments: &[ShareCommitment], index: u8) -> Option<ProjectivePoint> {
+	let x = Scalar::from(index as u64);
+	commitments.iter().rev().try_fold(ProjectivePoint::IDENTITY, |acc, commitment| {
+		Some(acc * x + decode_point(commitment)?)
+	})
+}
+
+/// Соответствует ли раскрытый шар обязательствам
+pub fn verify_share(commitments: &[ShareCommitment], index: u8, share: &ShareScalar) -> bool {
+	match (public_share(commitments, index), decode_scalar(share)) {
+		(Some(expected), Some(share)) => ProjectivePoint::GENERATOR * share == expected,
+		_ => false,
+	}
+}
+
+fn challenge(context: &[u8], public: &ProjectivePoint, commitment: &ShareCommitment) -> Scalar {
+	let hash = (context, encode_point(public), commitment).using_encoded(sp_io::hashing::blake2_256);
+	<Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(hash))
+}
+
+/// Проверяет доказательство знания шара с индексом `index`. `context`
+/// привязывает доказательство к владельцу и контакту.
+pub fn verify_share_proof(
+	commitments: &[ShareCommitment],
+	index: u8,
+	context: &[u8],
+	proof: &ShareProof,
+) -> bool {
+	let (Some(public), Some(r), Some(z)) = (
+		public_share(commitments, index),
+		decode_point(&proof.commitment),
+		decode_scalar(&proof.response),
+	) else {
+		return false;
+	};
+	let c = challenge(context, &public, &proof.commitment);
+	ProjectivePoint::GENERATOR * z == r + public * c
+}
+
+/// Построение шаров и доказательств для тестов и бенчмарков
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod dealer {
+	use super::*;
+	use sp_std::vec::Vec;
+
+	/// Скаляр, детерминированно полученный из `seed`
+	pub fn scalar(seed: impl Encode) -> Scalar {
+		let hash = (b"aura-identity:vss", seed).using_encoded(sp_io::hashing::blake2_256);
+		<Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(hash))
+	}
+
+	pub fn commitments(coefficients: &[Scalar]) -> Vec<ShareCommitment> {
+		coefficients.iter().map(|a| encode_point(&(ProjectivePoint::GENERATOR * a))).collect()
+	}
+
+	/// Шар `f(index)`
+	pub fn share(coefficients: &[Scalar], index: u8) -> Scalar {
+		let x = Scalar::from(index as u64);
+		coefficients.iter().rev().fold(Scalar::ZERO, |acc, a| acc * x + a)
+	}
+
+	pub fn share_bytes(share: &Scalar) -> ShareScalar {
+		share.to_repr().into()
+	}
+
+	pub fn prove(share: &Scalar, context: &[u8]) -> ShareProof {
+		let k = scalar((context, share.to_repr().as_slice()));
+		let commitment = encode_point(&(ProjectivePoint::GENERATOR * k));
+		let public = ProjectivePoint::GENERATOR * share;
+		let c = challenge(context, &public, &commitment);
+		ShareProof { commitment, response: (k + c * share).to_repr().into() }
+	}
+}
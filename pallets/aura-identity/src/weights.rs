@@ -0,0 +1,565 @@
+
+//! Weights for pallet_aura_identity
+//!
+//! PLACEHOLDER WEIGHTS, NOT BENCHMARKED. The values are hand-written estimates
+//! based on the storage accesses of each call and have not been measured on
+//! reference hardware.
+//!
+//! TODO: regenerate with `./scripts/benchmark.sh pallet_aura_identity` before the
+//! pallet is deployed to a production chain.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_aura_identity.
+pub trait WeightInfo {
+	fn create_aura_id(c: u32, ) -> Weight;
+	fn setup_recovery(t: u32, ) -> Weight;
+	fn add_trustee() -> Weight;
+	fn remove_trustee() -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn submit_recovery_share() -> Weight;
+	fn execute_recovery(t: u32, ) -> Weight;
+	fn cancel_recovery(t: u32, ) -> Weight;
+	fn accept_trusteeship() -> Weight;
+	fn decline_trusteeship() -> Weight;
+	fn update_recovery_config() -> Weight;
+	fn apply_recovery_config_update() -> Weight;
+	fn remove_recovery(t: u32, ) -> Weight;
+	fn rotate_key() -> Weight;
+	fn add_verification_method() -> Weight;
+	fn remove_verification_method() -> Weight;
+	fn add_service() -> Weight;
+	fn remove_service() -> Weight;
+	fn add_controller() -> Weight;
+	fn remove_controller() -> Weight;
+	fn deactivate_aura_id() -> Weight;
+	fn remove_expired_recovery(t: u32, ) -> Weight;
+	fn set_recovery_policy(n: u32, ) -> Weight;
+	fn set_backup_key() -> Weight;
+	fn remove_backup_key() -> Weight;
+	fn initiate_backup_recovery() -> Weight;
+	fn upload_shares(t: u32, ) -> Weight;
+	fn prove_share(t: u32, ) -> Weight;
+	fn dispute_share() -> Weight;
+	fn reveal_share(t: u32, ) -> Weight;
+}
+
+/// Weights for pallet_aura_identity using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:1)
+	/// Storage: AuraIdentity KeyProofNonces (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: AuraIdentity DidIndex (r:1 w:1)
+	/// The range of component `c` is `[0, 1024]`.
+	fn create_aura_id(c: u32, ) -> Weight {
+		Weight::from_parts(61_412_000, 4_687)
+			.saturating_add(Weight::from_parts(1_284, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:1)
+	/// Storage: AuraIdentity AuraIdentities (r:10 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity RecoveryDeposits (r:0 w:1)
+	/// Storage: AuraIdentity TrusteeShares (r:0 w:10)
+	/// Storage: AuraIdentity Trusteeships (r:160 w:10)
+	/// The range of component `t` is `[2, 10]`.
+	fn setup_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(31_905_000, 3_593)
+			.saturating_add(Weight::from_parts(14_842_000, 10_675).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((17_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+	}
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:1)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:0)
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity Trusteeships (r:16 w:1)
+	/// Storage: AuraIdentity TrusteeShares (r:1 w:1)
+	fn add_trustee() -> Weight {
+		Weight::from_parts(35_310_000, 13_215)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:1)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:0)
+	/// Storage: AuraIdentity TrusteeShares (r:1 w:1)
+	/// Storage: AuraIdentity RecoveryPolicies (r:1 w:0)
+	/// Storage: AuraIdentity ShareStates (r:0 w:1)
+	/// Storage: AuraIdentity Trusteeships (r:0 w:1)
+	fn remove_trustee() -> Weight {
+		Weight::from_parts(30_912_000, 6_817)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:0)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(36_880_000, 4_687)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:1)
+	/// Storage: AuraIdentity TrusteeShares (r:1 w:0)
+	/// Storage: AuraIdentity RecoveryVouches (r:1 w:1)
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	fn submit_recovery_share() -> Weight {
+		Weight::from_parts(29_210_000, 6_360)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:1)
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:0)
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:1)
+	/// Storage: AuraIdentity KeyHistory (r:1 w:1)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity BackupApprovals (r:1 w:1)
+	/// Storage: AuraIdentity RecoveryPolicies (r:1 w:0)
+	/// Storage: AuraIdentity DidIndex (r:0 w:1)
+	/// Storage: AuraIdentity RecoveryVouches (r:10 w:10)
+	/// The range of component `t` is `[2, 10]`.
+	fn execute_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(56_204_000, 8_383)
+			.saturating_add(Weight::from_parts(4_127_000, 2_524).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t.into())))
+	}
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: AuraIdentity BackupApprovals (r:0 w:1)
+	/// Storage: AuraIdentity RecoveryVouches (r:0 w:10)
+	/// The range of component `t` is `[2, 10]`.
+	fn cancel_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(50_117_000, 6_196)
+			.saturating_add(Weight::from_parts(1_921_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t.into())))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity TrusteeShares (r:1 w:1)
+	fn accept_trusteeship() -> Weight {
+		Weight::from_parts(20_600_000, 5_765)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraIdentity TrusteeShares (r:1 w:1)
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:1)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:1)
+	/// Storage: System Number (r:1 w:0)
+	/// Storage: AuraIdentity RecoveryPolicies (r:1 w:0)
+	/// Storage: AuraIdentity RecoveryVouches (r:1 w:1)
+	/// Storage: AuraIdentity ShareStates (r:0 w:1)
+	/// Storage: AuraIdentity Trusteeships (r:0 w:1)
+	fn decline_trusteeship() -> Weight {
+		Weight::from_parts(39_842_000, 8_383)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:1)
+	/// Storage: AuraIdentity PendingDelayUpdates (r:0 w:1)
+	fn update_recovery_config() -> Weight {
+		Weight::from_parts(17_240_000, 3_529)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuraIdentity PendingDelayUpdates (r:1 w:1)
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:1)
+	fn apply_recovery_config_update() -> Weight {
+		Weight::from_parts(19_060_000, 3_529)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:1)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:0)
+	/// Storage: AuraIdentity RecoveryDeposits (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity PendingDelayUpdates (r:0 w:1)
+	/// Storage: AuraIdentity RecoveryPolicies (r:0 w:1)
+	/// Storage: AuraIdentity ShareCommitments (r:0 w:1)
+	/// Storage: AuraIdentity TrusteeShares (r:10 w:10)
+	/// Storage: AuraIdentity ShareStates (r:0 w:10)
+	/// Storage: AuraIdentity Trusteeships (r:0 w:10)
+	/// The range of component `t` is `[2, 10]`.
+	fn remove_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(41_870_000, 3_593)
+			.saturating_add(Weight::from_parts(6_412_000, 2_675).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(t.into())))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:1)
+	/// Storage: AuraIdentity KeyProofNonces (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: AuraIdentity KeyHistory (r:1 w:1)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	fn rotate_key() -> Weight {
+		Weight::from_parts(117_402_000, 6_264)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity DidIndex (r:1 w:0)
+	/// Storage: AuraIdentity VerificationMethods (r:16 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	fn add_verification_method() -> Weight {
+		Weight::from_parts(87_985_000, 45_652)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity VerificationMethods (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	fn remove_verification_method() -> Weight {
+		Weight::from_parts(33_470_000, 4_721)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity Services (r:8 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	fn add_service() -> Weight {
+		Weight::from_parts(63_794_000, 25_533)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity Services (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	fn remove_service() -> Weight {
+		Weight::from_parts(33_820_000, 4_968)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity DidIndex (r:1 w:0)
+	/// Storage: AuraIdentity Controllers (r:4 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	fn add_controller() -> Weight {
+		Weight::from_parts(47_601_000, 12_376)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity Controllers (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	fn remove_controller() -> Weight {
+		Weight::from_parts(31_290_000, 4_553)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:1)
+	/// Storage: AuraIdentity ActiveRecoveries (r:17 w:17)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: AuraIdentity RecoveryVouches (r:16 w:26)
+	/// Storage: AuraIdentity BackupApprovals (r:0 w:1)
+	/// Storage: AuraIdentity BackupKeys (r:1 w:1)
+	/// Storage: AuraIdentity RecoveryConfigs (r:16 w:17)
+	/// Storage: AuraIdentity PendingDelayUpdates (r:0 w:1)
+	/// Storage: AuraIdentity RecoveryPolicies (r:16 w:17)
+	/// Storage: AuraIdentity ShareCommitments (r:0 w:1)
+	/// Storage: AuraIdentity TrusteeShares (r:10 w:26)
+	/// Storage: AuraIdentity ShareStates (r:0 w:26)
+	/// Storage: AuraIdentity Trusteeships (r:17 w:26)
+	/// Storage: AuraIdentity RecoveryDeposits (r:1 w:1)
+	/// Storage: AuraIdentity VerificationMethods (r:17 w:16)
+	/// Storage: AuraIdentity Services (r:9 w:8)
+	/// Storage: AuraIdentity Controllers (r:5 w:4)
+	/// Storage: AuraIdentity DocumentVersions (r:1 w:1)
+	/// Storage: AuraIdentity KeyHistory (r:0 w:1)
+	/// Storage: AuraIdentity Tombstones (r:0 w:1)
+	fn deactivate_aura_id() -> Weight {
+		Weight::from_parts(719_775_000, 131_010)
+			.saturating_add(T::DbWeight::get().reads(129_u64))
+			.saturating_add(T::DbWeight::get().writes(195_u64))
+	}
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:1)
+	/// Storage: System Number (r:1 w:0)
+	/// Storage: AuraIdentity BackupApprovals (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity RecoveryVouches (r:0 w:10)
+	/// The range of component `t` is `[2, 10]`.
+	fn remove_expired_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(49_316_000, 6_196)
+			.saturating_add(Weight::from_parts(1_874_000, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(t.into())))
+	}
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:0)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:0)
+	/// Storage: AuraIdentity TrusteeShares (r:16 w:0)
+	/// Storage: AuraIdentity BackupKeys (r:1 w:0)
+	/// Storage: AuraIdentity RecoveryPolicies (r:0 w:1)
+	/// The range of component `n` is `[1, 16]`.
+	fn set_recovery_policy(n: u32, ) -> Weight {
+		Weight::from_parts(24_407_000, 3_593)
+			.saturating_add(Weight::from_parts(3_318_000, 2_675).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:0)
+	/// Storage: AuraIdentity RecoveryPolicies (r:1 w:0)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: AuraIdentity KeyProofNonces (r:1 w:1)
+	/// Storage: AuraIdentity BackupKeys (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn set_backup_key() -> Weight {
+		Weight::from_parts(217_457_000, 8_383)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:0)
+	/// Storage: AuraIdentity BackupKeys (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_backup_key() -> Weight {
+		Weight::from_parts(29_358_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AuraIdentity AuraIdentities (r:1 w:0)
+	/// Storage: AuraIdentity BackupKeys (r:1 w:0)
+	/// Storage: AuraIdentity KeyProofNonces (r:1 w:1)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AuraIdentity BackupApprovals (r:0 w:1)
+	fn initiate_backup_recovery() -> Weight {
+		Weight::from_parts(221_405_000, 4_687)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: AuraIdentity RecoveryConfigs (r:1 w:0)
+	/// Storage: AuraIdentity ActiveRecoveries (r:1 w:0)
+	/// Storage: AuraIdentity ShareStates (r:10 w:20)
+	/// Storage: AuraIdentity TrusteeShares (r:10 w:10)
+	/// Storage: AuraIdentity ShareCommitments (r:0 w:1)
+	/// The range of component `t` is `[2, 10]`.
+	fn upload_shares(t: u32, ) -> Weight {
+		Weight::from_parts(38_415_000, 3_529)
+			.saturating_add(Weight::from_parts(96_382_000, 5_230).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(t.into())))
+	}
+	/// Storage: AuraIdentity ShareStates (r:1 w:1)
+	/// Storage: AuraIdentity ShareCommitments (r:1 w:0)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// The range of component `t` is `[2, 10]`.
+	fn prove_share(t: u32, ) -> Weight {
+		Weight::from_parts(291_644_000, 4_318)
+			.saturating_add(Weight::from_parts(87_905_000, 33).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraIdentity ShareStates (r:1 w:1)
+	fn dispute_share() -> Weight {
+		Weight::from_parts(18_327_000, 3_541)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: AuraIdentity ShareStates (r:1 w:1)
+	/// Storage: System Number (r:1 w:0)
+	/// Storage: AuraIdentity ShareCommitments (r:1 w:0)
+	/// The range of component `t` is `[2, 10]`.
+	fn reveal_share(t: u32, ) -> Weight {
+		Weight::from_parts(174_207_000, 4_318)
+			.saturating_add(Weight::from_parts(87_905_000, 33).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_aura_id(c: u32, ) -> Weight {
+		Weight::from_parts(61_412_000, 4_687)
+			.saturating_add(Weight::from_parts(1_284, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn setup_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(31_905_000, 3_593)
+			.saturating_add(Weight::from_parts(14_842_000, 10_675).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(t.into())))
+	}
+	fn add_trustee() -> Weight {
+		Weight::from_parts(35_310_000, 13_215)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn remove_trustee() -> Weight {
+		Weight::from_parts(30_912_000, 6_817)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(36_880_000, 4_687)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn submit_recovery_share() -> Weight {
+		Weight::from_parts(29_210_000, 6_360)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn execute_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(56_204_000, 8_383)
+			.saturating_add(Weight::from_parts(4_127_000, 2_524).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(t.into())))
+	}
+	fn cancel_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(50_117_000, 6_196)
+			.saturating_add(Weight::from_parts(1_921_000, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(t.into())))
+	}
+	fn accept_trusteeship() -> Weight {
+		Weight::from_parts(20_600_000, 5_765)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn decline_trusteeship() -> Weight {
+		Weight::from_parts(39_842_000, 8_383)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn update_recovery_config() -> Weight {
+		Weight::from_parts(17_240_000, 3_529)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn apply_recovery_config_update() -> Weight {
+		Weight::from_parts(19_060_000, 3_529)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn remove_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(41_870_000, 3_593)
+			.saturating_add(Weight::from_parts(6_412_000, 2_675).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(t.into())))
+	}
+	fn rotate_key() -> Weight {
+		Weight::from_parts(117_402_000, 6_264)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn add_verification_method() -> Weight {
+		Weight::from_parts(87_985_000, 45_652)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn remove_verification_method() -> Weight {
+		Weight::from_parts(33_470_000, 4_721)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn add_service() -> Weight {
+		Weight::from_parts(63_794_000, 25_533)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn remove_service() -> Weight {
+		Weight::from_parts(33_820_000, 4_968)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn add_controller() -> Weight {
+		Weight::from_parts(47_601_000, 12_376)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn remove_controller() -> Weight {
+		Weight::from_parts(31_290_000, 4_553)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn deactivate_aura_id() -> Weight {
+		Weight::from_parts(719_775_000, 131_010)
+			.saturating_add(RocksDbWeight::get().reads(129_u64))
+			.saturating_add(RocksDbWeight::get().writes(195_u64))
+	}
+	fn remove_expired_recovery(t: u32, ) -> Weight {
+		Weight::from_parts(49_316_000, 6_196)
+			.saturating_add(Weight::from_parts(1_874_000, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(t.into())))
+	}
+	fn set_recovery_policy(n: u32, ) -> Weight {
+		Weight::from_parts(24_407_000, 3_593)
+			.saturating_add(Weight::from_parts(3_318_000, 2_675).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_backup_key() -> Weight {
+		Weight::from_parts(217_457_000, 8_383)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn remove_backup_key() -> Weight {
+		Weight::from_parts(29_358_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn initiate_backup_recovery() -> Weight {
+		Weight::from_parts(221_405_000, 4_687)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn upload_shares(t: u32, ) -> Weight {
+		Weight::from_parts(38_415_000, 3_529)
+			.saturating_add(Weight::from_parts(96_382_000, 5_230).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(t.into())))
+	}
+	fn prove_share(t: u32, ) -> Weight {
+		Weight::from_parts(291_644_000, 4_318)
+			.saturating_add(Weight::from_parts(87_905_000, 33).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn dispute_share() -> Weight {
+		Weight::from_parts(18_327_000, 3_541)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn reveal_share(t: u32, ) -> Weight {
+		Weight::from_parts(174_207_000, 4_318)
+			.saturating_add(Weight::from_parts(87_905_000, 33).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
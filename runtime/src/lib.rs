@@ -21,7 +21,7 @@ use sp_version::RuntimeVersion;
 
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, Everything},
+    traits::{ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, Contains},
     weights::{
         ConstantMultiplier, DispatchClass, Weight, WeightToFeeCoefficient, WeightToFeeCoefficients,
         WeightToFeePolynomial,
@@ -85,7 +85,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("aura-chain"),
     impl_name: create_runtime_str!("aura-chain"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 8,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 1,
@@ -137,12 +137,77 @@ parameter_types! {
     
     /// Период задержки для восстановления (24 часа при 6-секундных блоках)
     pub const DefaultRecoveryDelay: BlockNumber = 14400;
+    
+    /// Минимальный период задержки для восстановления (1 час)
+    pub const MinRecoveryDelay: BlockNumber = constants::time::HOURS;
+    
+    /// Максимальный период задержки для восстановления (60 дней)
+    pub const MaxRecoveryDelay: BlockNumber = 60 * constants::time::DAYS;
+    
+    /// Сколько запрос на восстановление действует после периода задержки (7 дней)
+    pub const RecoveryLifetime: BlockNumber = 7 * constants::time::DAYS;
+    
+    /// Сколько у владельца времени на ответ на жалобу на шар секрета (3 дня)
+    pub const ShareComplaintPeriod: BlockNumber = 3 * constants::time::DAYS;
+    
+    /// Сколько предыдущих ключей Aura ID хранится для проверки старых подписей
+    pub const MaxKeyHistory: u32 = 16;
+    
+    /// Ограничения DID документа
+    pub const MaxVerificationMethods: u32 = 16;
+    pub const MaxServices: u32 = 8;
+    pub const MaxControllers: u32 = 4;
+    
+    /// Депозит за элемент DID документа и за каждый его байт
+    pub const DocumentDepositBase: Balance = deposit(1, 0);
+    pub const DocumentByteDeposit: Balance = deposit(0, 1);
+    
+    /// Залог инициатора восстановления (10 AURA)
+    pub const RecoveryBond: Balance = 10 * UNIT;
+    
+    /// Доля залога, списываемая при отмене восстановления владельцем
+    pub const RecoveryBondSlash: Perbill = Perbill::from_percent(50);
+    
+    /// Казны пока нет - списанный залог получает владелец
+    pub const RecoverySlashBeneficiary: Option<AccountId> = None;
+}
+
+parameter_types! {
+    /// Депозит издателя учетных данных (10 AURA)
+    pub const IssuerDeposit: Balance = 10 * UNIT;
+    
+    /// Депозит за схему и за каждый байт списка статусов
+    pub const SchemaDeposit: Balance = deposit(1, 256);
+    pub const StatusListByteDeposit: Balance = deposit(0, 1);
+    
+    /// Список статусов до 16 КиБ (131 072 статуса, минимум W3C Bitstring Status List)
+    pub const MaxStatusListSize: u32 = 16_384;
+    pub const MaxStatusLists: u32 = 64;
+    pub const MaxStatusUpdates: u32 = 256;
+    
+    /// Депозит за утверждение: запись и индекс по издателю
+    pub const ClaimDeposit: Balance = deposit(2, 224);
+    
+    /// Утверждений об одном DID
+    pub const MaxClaimsPerSubject: u32 = 64;
+}
+
+parameter_types! {
+    /// Депозит за имя: регистрация и обратный индекс
+    pub const HandleDeposit: Balance = deposit(2, 128);
+    
+    /// Длина имени
+    pub const MinHandleLength: u32 = 3;
+    pub const MaxHandleLength: u32 = 32;
+    
+    /// Имя регистрируется на год
+    pub const HandleRegistrationPeriod: BlockNumber = 365 * constants::time::DAYS;
 }
 
 /// Implement the system config for the runtime.
 impl frame_system::Config for Runtime {
     /// The basic call filter to use in dispatchable.
-    type BaseCallFilter = Everything;
+    type BaseCallFilter = AuraIdentityMigrationFilter;
     /// Block & extrinsics weights: base values and limits.
     type BlockWeights = RuntimeBlockWeights;
     /// The maximum length of a block (in bytes).
@@ -279,8 +344,91 @@ impl pallet_aura_identity::Config for Runtime {
     /// Максимальное количество доверенных контактов
     type MaxTrustees = ConstU32<10>;
     
+    /// У скольких аккаунтов один аккаунт может быть доверенным контактом
+    type MaxTrusteeships = ConstU32<16>;
+    
     /// Депозит для настройки recovery
     type RecoveryDeposit = ConstU128<{ 1 * UNIT }>;
+    
+    /// Залог инициатора восстановления
+    type RecoveryBond = RecoveryBond;
+    
+    /// Доля залога, списываемая при отмене
+    type RecoveryBondSlash = RecoveryBondSlash;
+    
+    /// Получатель списанного залога
+    type SlashBeneficiary = RecoverySlashBeneficiary;
+    
+    /// Период задержки восстановления по умолчанию и его границы
+    type DefaultRecoveryDelay = DefaultRecoveryDelay;
+    type MinRecoveryDelay = MinRecoveryDelay;
+    type MaxRecoveryDelay = MaxRecoveryDelay;
+    
+    /// Время жизни запроса на восстановление после периода задержки
+    type RecoveryLifetime = RecoveryLifetime;
+    type ShareComplaintPeriod = ShareComplaintPeriod;
+    
+    /// Глубина истории ключей
+    type MaxKeyHistory = MaxKeyHistory;
+    
+    /// DID документ: лимиты и депозиты
+    type MaxVerificationMethods = MaxVerificationMethods;
+    type MaxServices = MaxServices;
+    type MaxControllers = MaxControllers;
+    type DocumentDepositBase = DocumentDepositBase;
+    type DocumentByteDeposit = DocumentByteDeposit;
+    
+    /// Принудительная деактивация Aura ID
+    type ForceOrigin = EnsureRoot<AccountId>;
+    
+    /// DID в вызовах можно указывать именами из реестра имен
+    type DidLookup = pallet_aura_handles::DidLookup<Runtime>;
+    
+    /// Доверенных контактов можно указывать именами из реестра имен
+    type TrusteeLookup = AuraHandles;
+    
+    /// Оценочные веса паллета: бенчмарки еще не запускались (см. weights.rs)
+    type WeightInfo = pallet_aura_identity::weights::SubstrateWeight<Runtime>;
+}
+
+// ========== КОНФИГУРАЦИЯ AURA CREDENTIALS ==========
+
+impl pallet_aura_credentials::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    
+    /// Депозиты издателей, схем и списков статусов
+    type IssuerDeposit = IssuerDeposit;
+    type SchemaDeposit = SchemaDeposit;
+    type StatusListByteDeposit = StatusListByteDeposit;
+    
+    /// Ограничения списков статусов
+    type MaxStatusListSize = MaxStatusListSize;
+    type MaxStatusLists = MaxStatusLists;
+    type MaxStatusUpdates = MaxStatusUpdates;
+    
+    /// Депозит за утверждение о другом Aura ID и их количество на субъект
+    type ClaimDeposit = ClaimDeposit;
+    type MaxClaimsPerSubject = MaxClaimsPerSubject;
+    
+    /// Оценочные веса паллета: бенчмарки еще не запускались (см. weights.rs)
+    type WeightInfo = pallet_aura_credentials::weights::SubstrateWeight<Runtime>;
+}
+
+// ========== КОНФИГУРАЦИЯ AURA HANDLES ==========
+
+impl pallet_aura_handles::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    
+    /// Депозит и ограничения имен
+    type HandleDeposit = HandleDeposit;
+    type MinHandleLength = MinHandleLength;
+    type MaxHandleLength = MaxHandleLength;
+    
+    /// Срок регистрации и продления
+    type RegistrationPeriod = HandleRegistrationPeriod;
+    
+    /// Оценочные веса паллета: бенчмарки еще не запускались (см. weights.rs)
+    type WeightInfo = pallet_aura_handles::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -298,9 +446,25 @@ construct_runtime!(
         TransactionPayment: pallet_transaction_payment,
         AuraIdentity: pallet_aura_identity,
         Sudo: pallet_sudo,
+        AuraCredentials: pallet_aura_credentials,
+        AuraHandles: pallet_aura_handles,
     }
 );
 
+/// Пока идет многоблочная миграция Aura Identity, вызовы паллета отклоняются:
+/// часть записей еще хранится в старом формате.
+pub struct AuraIdentityMigrationFilter;
+impl Contains<RuntimeCall> for AuraIdentityMigrationFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        !matches!(call, RuntimeCall::AuraIdentity(_)) || !AuraIdentity::migration_ongoing()
+    }
+}
+
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+    pallet_aura_identity::migrations::v2::LazyMigrateToV2<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -308,8 +472,25 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
+#[cfg(feature = "runtime-benchmarks")]
+#[macro_use]
+extern crate frame_benchmarking;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benches {
+    define_benchmarks!(
+        [frame_benchmarking, BaselineBench::<Runtime>]
+        [frame_system, SystemBench::<Runtime>]
+        [pallet_balances, Balances]
+        [pallet_aura_identity, AuraIdentity]
+        [pallet_aura_credentials, AuraCredentials]
+        [pallet_aura_handles, AuraHandles]
+    );
+}
+
 // Implement various runtime APIs
 impl_runtime_apis! {
     // System API
@@ -344,6 +525,116 @@ impl_runtime_apis! {
         }
     }
     
+    // Aura Identity API
+    impl pallet_aura_identity_runtime_api::AuraIdentityRuntimeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn identity(account: AccountId) -> Option<pallet_aura_identity::AuraIdRecord<BlockNumber>> {
+            AuraIdentity::get_aura_id(account)
+        }
+
+        fn account_by_did(did: [u8; 32]) -> Option<AccountId> {
+            AuraIdentity::account_by_did(&did)
+        }
+
+        fn recovery_config(account: AccountId) -> Option<pallet_aura_identity::RecoveryConfig<Balance>> {
+            AuraIdentity::get_recovery_config(account)
+        }
+
+        fn trustees(account: AccountId) -> Vec<pallet_aura_identity::TrusteeInfo<AccountId>> {
+            AuraIdentity::trustees(&account)
+        }
+
+        fn active_recovery(
+            account: AccountId,
+        ) -> Option<pallet_aura_identity::RecoveryStatus<AccountId, Balance, BlockNumber>> {
+            AuraIdentity::recovery_status(&account)
+        }
+
+        fn key_at(account: AccountId, at: BlockNumber) -> Option<pallet_aura_identity::AuraPublicKey> {
+            AuraIdentity::key_at(&account, at)
+        }
+
+        fn tombstone(did: [u8; 32]) -> Option<pallet_aura_identity::Tombstone<AccountId, BlockNumber>> {
+            AuraIdentity::tombstone(did)
+        }
+
+        fn recovery_policy(account: AccountId) -> Option<pallet_aura_identity::RecoveryPolicy<AccountId>> {
+            AuraIdentity::recovery_policy(account)
+        }
+
+        fn backup_key(account: AccountId) -> Option<pallet_aura_identity::BackupKey<Balance>> {
+            AuraIdentity::backup_key(account)
+        }
+
+        fn share_commitments(account: AccountId) -> Option<pallet_aura_identity::ShareCommitmentSet<BlockNumber>> {
+            AuraIdentity::share_commitments(account)
+        }
+
+        fn share_states(account: AccountId) -> Vec<(AccountId, pallet_aura_identity::ShareInfo<BlockNumber>)> {
+            AuraIdentity::share_states(&account)
+        }
+
+        fn did_document(did: [u8; 32]) -> Option<pallet_aura_identity::DidDocument<Balance, BlockNumber>> {
+            AuraIdentity::did_document(&did)
+        }
+
+        fn invalid_shares(account: AccountId) -> Vec<AccountId> {
+            AuraIdentity::invalid_shares(&account)
+        }
+    }
+
+    // Aura Credentials API
+    impl pallet_aura_credentials_runtime_api::AuraCredentialsRuntimeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn issuer(did: [u8; 32]) -> Option<pallet_aura_credentials::Issuer<AccountId, Balance, BlockNumber>> {
+            AuraCredentials::issuer(did)
+        }
+
+        fn schema(schema_hash: [u8; 32]) -> Option<pallet_aura_credentials::CredentialSchema<Balance, BlockNumber>> {
+            AuraCredentials::schema(schema_hash)
+        }
+
+        fn credential_status(
+            issuer: [u8; 32],
+            list_id: u32,
+            index: u32,
+        ) -> Option<pallet_aura_credentials::CredentialStatus<BlockNumber>> {
+            AuraCredentials::credential_status(&issuer, list_id, index)
+        }
+
+        fn claims_of(
+            subject: [u8; 32],
+            include_hidden: bool,
+        ) -> Vec<([u8; 32], pallet_aura_credentials::Claim<AccountId, Balance, BlockNumber>)> {
+            AuraCredentials::claims_of(&subject, include_hidden)
+        }
+
+        fn claims_issued_by(
+            issuer: [u8; 32],
+            start_after: Option<[u8; 32]>,
+            limit: u32,
+        ) -> Vec<([u8; 32], [u8; 32])> {
+            AuraCredentials::claims_issued_by(&issuer, start_after, limit)
+        }
+    }
+
+    // Aura Handles API
+    impl pallet_aura_handles_runtime_api::AuraHandlesRuntimeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn resolve(name: Vec<u8>) -> Option<AccountId> {
+            AuraHandles::resolve(&name)
+        }
+
+        fn resolve_did(name: Vec<u8>) -> Option<[u8; 32]> {
+            AuraHandles::resolve_did(&name)
+        }
+
+        fn handle_of(account: AccountId) -> Option<Vec<u8>> {
+            AuraHandles::handle_of(&account).map(|handle| handle.into_inner())
+        }
+
+        fn handle(name: Vec<u8>) -> Option<pallet_aura_handles::HandleRecord<AccountId, Balance, BlockNumber>> {
+            AuraHandles::normalize(&name).ok().and_then(AuraHandles::handle)
+        }
+    }
+    
     // Aura API
     impl sp_consensus_aura::AuraApi<Block, sp_consensus_aura::sr25519::AuthorityId> for Runtime {
         fn slot_duration() -> sp_consensus_aura::SlotDuration {
@@ -395,6 +686,47 @@ impl_runtime_apis! {
             SessionKeys::decode_into_raw_public_keys(&encoded)
         }
     }
+    
+    // Benchmark API
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn benchmark_metadata(extra: bool) -> (
+            Vec<frame_benchmarking::BenchmarkList>,
+            Vec<frame_support::traits::StorageInfo>,
+        ) {
+            use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
+            use frame_support::traits::StorageInfoTrait;
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use baseline::Pallet as BaselineBench;
+
+            let mut list = Vec::<BenchmarkList>::new();
+            list_benchmarks!(list, extra);
+
+            let storage_info = AllPalletsWithSystem::storage_info();
+
+            (list, storage_info)
+        }
+
+        fn dispatch_benchmark(
+            config: frame_benchmarking::BenchmarkConfig
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{baseline, Benchmarking, BenchmarkBatch, TrackedStorageKey};
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use baseline::Pallet as BaselineBench;
+
+            impl frame_system_benchmarking::Config for Runtime {}
+            impl baseline::Config for Runtime {}
+
+            use frame_support::traits::WhitelistedStorageKeys;
+            let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&config, &whitelist);
+            add_benchmarks!(params, batches);
+
+            Ok(batches)
+        }
+    }
 }
 
 // Weight units
@@ -0,0 +1,24 @@
+#!/bin/bash
+# Перегенерирует weights.rs паллета по результатам бенчмарков.
+# Запускать на эталонном железе: ./scripts/benchmark.sh pallet_aura_identity
+
+set -e
+
+PALLET=${1:-pallet_aura_identity}
+PALLET_DIR=$(echo "${PALLET#pallet_}" | tr '_' '-')
+
+echo "🔨 Building node with runtime-benchmarks..."
+cargo build --release --features runtime-benchmarks
+
+echo "⏱  Benchmarking ${PALLET}..."
+./target/release/aura-chain-node benchmark pallet \
+    --chain=dev \
+    --steps=50 \
+    --repeat=20 \
+    --pallet="${PALLET}" \
+    --extrinsic='*' \
+    --execution=wasm \
+    --wasm-execution=compiled \
+    --output="./pallets/${PALLET_DIR}/src/weights.rs"
+
+echo "✅ Weights written to pallets/${PALLET_DIR}/src/weights.rs"